@@ -99,3 +99,87 @@ fn test_spath_is_likely_text() {
 		);
 	}
 }
+
+#[cfg(unix)]
+#[test]
+fn test_spath_meta_unix_fields_and_setters() -> Result<(), Box<dyn std::error::Error>> {
+	// -- Setup & Fixtures
+	let dir = SPath::new("target/tests-tmp/spath-meta-unix");
+	crate::ensure_dir(&dir)?;
+	let file = dir.join("run.sh");
+	std::fs::write(&file, "#!/bin/sh\n")?;
+	let link = dir.join("run-link.sh");
+	if link.as_std_path().is_symlink() {
+		std::fs::remove_file(&link)?;
+	}
+	std::os::unix::fs::symlink("run.sh", &link)?;
+
+	// -- Exec
+	file.set_permissions(0o644)?;
+	file.make_executable()?;
+	file.set_modified(1_000_000_000_000_000)?;
+	let meta = file.meta()?;
+	let link_meta = link.meta()?;
+	let link_meta_no_follow = link.meta_no_follow()?;
+
+	// -- Check
+	assert_eq!(meta.permissions_mode(), 0o755);
+	assert!(meta.is_executable());
+	assert_eq!(meta.modified_epoch_us, 1_000_000_000_000_000);
+	assert!(meta.nlink >= 1);
+	assert!(meta.inode > 0);
+	assert!(!meta.is_symlink);
+	assert!(link_meta.is_symlink);
+	assert_eq!(link_meta.inode, meta.inode);
+	assert!(link_meta.is_file);
+	assert!(link_meta_no_follow.is_symlink);
+	assert!(!link_meta_no_follow.is_file);
+	assert_ne!(link_meta_no_follow.inode, meta.inode);
+
+	Ok(())
+}
+
+#[test]
+fn test_spath_meta_created_real_or_fallback() -> Result<(), Box<dyn std::error::Error>> {
+	// -- Setup & Fixtures
+	let file = SPath::new("target/tests-tmp/spath-meta-created/file.txt");
+	crate::ensure_file_dir(&file)?;
+	std::fs::write(&file, "some content")?;
+	let std_created = std::fs::metadata(&file)?.created().ok();
+
+	// -- Exec
+	file.set_modified(1_000_000_000_000_000)?;
+	let meta = file.meta()?;
+
+	// -- Check
+	assert_eq!(meta.created_is_real, std_created.is_some());
+	if meta.created_is_real {
+		assert_ne!(meta.created_epoch_us, meta.modified_epoch_us);
+	} else {
+		assert_eq!(meta.created_epoch_us, meta.modified_epoch_us);
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_spath_expand_and_contract_home() -> Result<(), Box<dyn std::error::Error>> {
+	// -- Setup & Fixtures
+	let home = crate::home_dir()?;
+	let vars: std::collections::HashMap<String, String> = [("PROJECT".to_string(), "demo".to_string())].into();
+	let path = SPath::new("~/data/${PROJECT}/out");
+
+	// -- Exec
+	let expanded = path.expand_with(&vars)?;
+	let contracted = expanded.contract_home();
+	let strict = SPath::new("~/$UNDEFINED_SFS_VAR").expand_with(crate::ExpandOptions::default().with_strict(true));
+
+	// -- Check
+	assert_eq!(expanded, home.join("data/demo/out"));
+	assert_eq!(contracted.as_str(), "~/data/demo/out");
+	assert_eq!(home.contract_home().as_str(), "~");
+	assert_eq!(SPath::new("/not-home/x").contract_home().as_str(), "/not-home/x");
+	assert!(matches!(strict, Err(crate::Error::PathExpandVarUndefined { .. })));
+
+	Ok(())
+}
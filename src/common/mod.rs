@@ -2,10 +2,12 @@
 
 mod pretty;
 mod smeta;
+mod smeta_cache;
 mod system;
 
 pub use pretty::*;
 pub use smeta::*;
+pub use smeta_cache::*;
 pub use system::*;
 
 // endregion: --- Modules
@@ -2,10 +2,13 @@
 /// All fields are guaranteed to be present.
 #[derive(Debug, Clone)]
 pub struct SMeta {
-	/// Creation time since the Unix epoch in microseconds.
-	/// If unavailable, this may fall back to the modification time.
+	/// Creation (birth) time since the Unix epoch in microseconds.
+	/// If unavailable (filesystem or platform), this falls back to the modification time.
 	pub created_epoch_us: i64,
 
+	/// True if `created_epoch_us` is the real creation time, false if it is the modification time fallback.
+	pub created_is_real: bool,
+
 	/// Last modification time since the Unix epoch in microseconds.
 	pub modified_epoch_us: i64,
 
@@ -17,4 +20,42 @@ pub struct SMeta {
 
 	/// Whether the path is a directory.
 	pub is_dir: bool,
+
+	/// Whether the path itself is a symlink (from `symlink_metadata`, the other fields follow the link).
+	pub is_symlink: bool,
+
+	/// Last access time since the Unix epoch in microseconds.
+	/// If unavailable, this falls back to the modification time.
+	pub accessed_epoch_us: i64,
+
+	/// Unix mode (file type and permission bits, e.g., `0o100644`). Will be 0 on non-unix.
+	pub mode: u32,
+
+	/// Unix user id of the owner. Will be 0 on non-unix.
+	pub uid: u32,
+
+	/// Unix group id of the owner. Will be 0 on non-unix.
+	pub gid: u32,
+
+	/// Unix inode number. Will be 0 on non-unix.
+	pub inode: u64,
+
+	/// Unix device id of the filesystem. Will be 0 on non-unix.
+	pub dev: u64,
+
+	/// Number of hard links. Will be 0 on non-unix.
+	pub nlink: u64,
+}
+
+/// Unix helpers
+impl SMeta {
+	/// The permission bits of the mode (e.g., `0o755`).
+	pub fn permissions_mode(&self) -> u32 {
+		self.mode & 0o7777
+	}
+
+	/// Whether any of the execute bits (user, group, other) is set.
+	pub fn is_executable(&self) -> bool {
+		self.is_file && self.mode & 0o111 != 0
+	}
 }
@@ -0,0 +1,292 @@
+use crate::{Error, ListOptions, Result, SEvent, SMeta, SPath, iter_files};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A cache of `SMeta` keyed by path, to avoid re-stating the same files on repeated passes over a tree.
+///
+/// - Entries expire after the optional TTL (`with_ttl`), otherwise they stay until invalidated.
+/// - Invalidate with `invalidate(...)`, `invalidate_dir(...)`, or from the `SWatcher` events with `invalidate_events(...)`.
+/// - Populate while listing with `list_files_with_meta(...)`.
+///
+/// Note: Keys are the absolute paths (relative paths are resolved from the current directory),
+///       so that paths from listings and watch events match.
+#[derive(Debug, Default)]
+pub struct SMetaCache {
+	ttl: Option<Duration>,
+	entries: HashMap<SPath, CachedMeta>,
+}
+
+#[derive(Debug)]
+struct CachedMeta {
+	meta: SMeta,
+	cached_at: Instant,
+}
+
+/// Constructors
+impl SMetaCache {
+	/// Creates a cache without TTL (entries stay until invalidated).
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn with_ttl(mut self, ttl: Duration) -> Self {
+		self.ttl = Some(ttl);
+		self
+	}
+}
+
+/// Getters & Setters
+impl SMetaCache {
+	/// Returns the cached `SMeta` if still fresh, otherwise gets it with `SPath::meta()` and caches it.
+	pub fn get(&mut self, path: &SPath) -> Result<SMeta> {
+		let key = cache_key(path)?;
+		if let Some(meta) = self.fresh(&key) {
+			return Ok(meta.clone());
+		}
+
+		let meta = key.meta()?;
+		self.insert_key(key, meta.clone());
+		Ok(meta)
+	}
+
+	/// Returns the cached `SMeta` only if present and still fresh (never stats).
+	pub fn get_cached(&self, path: &SPath) -> Option<&SMeta> {
+		let key = cache_key(path).ok()?;
+		self.fresh(&key)
+	}
+
+	/// Caches an already computed `SMeta` for this path.
+	pub fn insert(&mut self, path: &SPath, meta: SMeta) -> Result<()> {
+		let key = cache_key(path)?;
+		self.insert_key(key, meta);
+		Ok(())
+	}
+
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	fn insert_key(&mut self, key: SPath, meta: SMeta) {
+		self.entries.insert(
+			key,
+			CachedMeta {
+				meta,
+				cached_at: Instant::now(),
+			},
+		);
+	}
+
+	fn fresh(&self, key: &SPath) -> Option<&SMeta> {
+		let cached = self.entries.get(key)?;
+		match self.ttl {
+			Some(ttl) if cached.cached_at.elapsed() > ttl => None,
+			_ => Some(&cached.meta),
+		}
+	}
+}
+
+/// Invalidation
+impl SMetaCache {
+	/// Removes the entry of this path. Returns true if there was one.
+	pub fn invalidate(&mut self, path: &SPath) -> bool {
+		match cache_key(path) {
+			Ok(key) => self.entries.remove(&key).is_some(),
+			Err(_) => false,
+		}
+	}
+
+	/// Removes the entries of this path and of all the paths below it (component-aware).
+	/// Returns the number of removed entries.
+	pub fn invalidate_dir(&mut self, dir: &SPath) -> usize {
+		let Ok(key) = cache_key(dir) else {
+			return 0;
+		};
+		let before = self.entries.len();
+		self.entries.retain(|path, _| !path.starts_with(&key));
+		before - self.entries.len()
+	}
+
+	/// Invalidates the paths (and the paths below them, e.g., for a removed or renamed directory)
+	/// of the `SWatcher` events. Returns the number of removed entries.
+	///
+	/// Note: Done in one pass over the cache (each entry is looked up with its ancestors in the event paths).
+	pub fn invalidate_events(&mut self, events: &[SEvent]) -> usize {
+		let event_keys: HashSet<String> = events
+			.iter()
+			.filter_map(|event| cache_key(&event.spath).ok())
+			.map(String::from)
+			.collect();
+		if event_keys.is_empty() {
+			return 0;
+		}
+
+		let before = self.entries.len();
+		self.entries
+			.retain(|path, _| !path.path().ancestors().any(|ancestor| event_keys.contains(ancestor.as_str())));
+		before - self.entries.len()
+	}
+
+	/// Removes the expired entries (per TTL). Returns the number of removed entries.
+	pub fn purge_expired(&mut self) -> usize {
+		let Some(ttl) = self.ttl else {
+			return 0;
+		};
+		let before = self.entries.len();
+		self.entries.retain(|_, cached| cached.cached_at.elapsed() <= ttl);
+		before - self.entries.len()
+	}
+
+	pub fn clear(&mut self) {
+		self.entries.clear();
+	}
+}
+
+/// Listing
+impl SMetaCache {
+	/// Lists the files (same as `list_files`) with their `SMeta`,
+	/// from the cache when fresh, otherwise from the metadata of the walk entry (and cached).
+	pub fn list_files_with_meta(
+		&mut self,
+		dir: impl AsRef<Path>,
+		include_globs: Option<&[&str]>,
+		list_options: Option<ListOptions<'_>>,
+	) -> Result<Vec<(SPath, SMeta)>> {
+		let mut res = Vec::new();
+		for (file, entry) in iter_files(dir, include_globs, list_options)?.into_entries() {
+			let key = cache_key(&file)?;
+			let meta = match self.fresh(&key) {
+				Some(meta) => meta.clone(),
+				None => {
+					let metadata = entry
+						.metadata()
+						.map_err(|ex| Error::CantGetMetadata((&file, std::io::Error::from(ex)).into()))?;
+					// NOTE: The listed files are never symlinks (the walk does not follow them)
+					let meta = file.meta_from_metadata(&metadata, false)?;
+					self.insert_key(key, meta.clone());
+					meta
+				}
+			};
+			res.push((file, meta));
+		}
+
+		Ok(res)
+	}
+}
+
+// region:    --- Support
+
+fn cache_key(path: &SPath) -> Result<SPath> {
+	if path.is_absolute() {
+		return Ok(path.collapse());
+	}
+	let abs = std::path::absolute(path).map_err(|ex| Error::CantGetMetadata((path, ex).into()))?;
+	Ok(SPath::from_std_path_buf(abs)?.into_collapsed())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{SEventKind, ensure_dir};
+	use std::fs;
+
+	#[test]
+	fn test_common_smeta_cache_invalidate_events() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/smeta-cache-events");
+		ensure_dir(&dir)?;
+		let file = dir.join("a.txt");
+		fs::write(&file, "1")?;
+		fs::write(dir.join("b.txt"), "22")?;
+		let mut cache = SMetaCache::new();
+
+		// -- Exec
+		let listed = cache.list_files_with_meta(&dir, Some(&["*.txt"]), None)?;
+		fs::write(&file, "333")?;
+		let stale = cache.get(&file)?;
+		let abs_file = SPath::from_std_path_buf(std::path::absolute(&file)?)?;
+		let removed = cache.invalidate_events(&[SEvent {
+			spath: abs_file,
+			skind: SEventKind::Modify,
+		}]);
+		let fresh = cache.get(&file)?;
+
+		// -- Check
+		assert_eq!(listed.len(), 2);
+		assert_eq!(cache.len(), 2);
+		assert_eq!(stale.size, 1, "should still be the cached meta");
+		assert_eq!(removed, 1);
+		assert_eq!(fresh.size, 3);
+		assert_eq!(cache.invalidate_dir(&dir), 2);
+		assert!(cache.is_empty());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_common_smeta_cache_list_and_invalidate_dir_events() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/smeta-cache-dir-events");
+		ensure_dir(dir.join("sub/deep"))?;
+		fs::write(dir.join("a.txt"), "1")?;
+		fs::write(dir.join("sub/b.txt"), "22")?;
+		fs::write(dir.join("sub/deep/c.txt"), "333")?;
+		let mut cache = SMetaCache::new();
+		let abs = |path: SPath| -> Result<SPath> { Ok(SPath::from_std_path_buf(std::path::absolute(&path)?)?) };
+
+		// -- Exec
+		let listed = cache.list_files_with_meta(&dir, Some(&["**/*.txt"]), None)?;
+		let cached_c = cache.get_cached(&dir.join("sub/deep/c.txt")).map(|meta| meta.size);
+		let removed = cache.invalidate_events(&[
+			SEvent {
+				spath: abs(dir.join("sub"))?,
+				skind: SEventKind::Remove,
+			},
+			SEvent {
+				spath: abs(dir.join("sub/deep/c.txt"))?,
+				skind: SEventKind::Remove,
+			},
+		]);
+
+		// -- Check
+		assert_eq!(listed.len(), 3);
+		assert_eq!(cached_c, Some(3), "the listing must fill the cache");
+		assert_eq!(removed, 2, "sub/b.txt and sub/deep/c.txt");
+		assert!(cache.get_cached(&dir.join("a.txt")).is_some());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_common_smeta_cache_ttl() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = SPath::new("target/tests-tmp/smeta-cache-ttl/a.txt");
+		crate::ensure_file_dir(&file)?;
+		fs::write(&file, "1")?;
+		let mut cache = SMetaCache::new().with_ttl(Duration::ZERO);
+
+		// -- Exec
+		cache.get(&file)?;
+		std::thread::sleep(Duration::from_millis(2));
+		let cached = cache.get_cached(&file).is_some();
+		let purged = cache.purge_expired();
+
+		// -- Check
+		assert!(!cached, "should be expired");
+		assert_eq!(purged, 1);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -13,6 +13,11 @@ pub enum Error {
 	PathNotUtf8(String),
 	#[display("Home directory not found")]
 	HomeDirNotFound,
+	#[display("Path '{path}' has an undefined variable '{var}'")]
+	PathExpandVarUndefined {
+		path: String,
+		var: String,
+	},
 	#[display("Path has no file name: '{_0}'")]
 	PathHasNoFileName(String),
 	#[display("Strip Prefix fail. Path '{path}' does not a base of '{prefix}'")]
@@ -34,12 +39,34 @@ pub enum Error {
 	FileCantCreate(PathAndCause),
 	#[display("File path has no parent directory: '{_0}'")]
 	FileHasNoParent(String),
+	#[display("Cannot lock file '{}'\nCause: {}", _0.path, _0.cause)]
+	FileCantLock(PathAndCause),
+	#[display("File format not supported for extension '{ext}' (path: '{path}')")]
+	FileFormatNotSupported {
+		path: String,
+		ext: String,
+	},
+	#[display("File format for extension '{ext}' requires the '{feature}' feature (path: '{path}')")]
+	FileFormatFeatureNotEnabled {
+		path: String,
+		ext: String,
+		feature: &'static str,
+	},
 
 	// -- Remove
 	#[display("File not safe to remove.\nPath: '{}'\nCause: {}", _0.path, _0.cause)]
 	FileNotSafeToRemove(PathAndCause),
 	#[display("Directory not safe to remove.\nPath: '{}'\nCause: {}", _0.path, _0.cause)]
 	DirNotSafeToRemove(PathAndCause),
+	#[display(
+		"Directory not fully removed '{path}' ({} paths could not be removed)\n{}",
+		failed.len(),
+		fmt_path_and_causes(failed)
+	)]
+	DirNotFullyRemoved {
+		path: String,
+		failed: Vec<PathAndCause>,
+	},
 
 	// -- Trash
 	#[display("File not safe to trash.\nPath: '{}'\nCause: {}", _0.path, _0.cause)]
@@ -48,6 +75,10 @@ pub enum Error {
 	DirNotSafeToTrash(PathAndCause),
 	#[display("Cannot trash path '{}'\nCause: {}", _0.path, _0.cause)]
 	CantTrash(PathAndCause),
+	#[display("Cannot list trashed items below '{}'\nCause: {}", _0.path, _0.cause)]
+	CantListTrashed(PathAndCause),
+	#[display("Cannot restore trashed item to '{}'\nCause: {}", _0.path, _0.cause)]
+	CantRestoreTrashed(PathAndCause),
 
 	// -- Sort
 	#[display("Cannot sort by globs.\nCause: {cause}")]
@@ -60,6 +91,8 @@ pub enum Error {
 	CantGetMetadata(PathAndCause),
 	#[display("Cannot get 'modified' metadata for path '{}'\nCause: {}", _0.path, _0.cause)]
 	CantGetMetadataModified(PathAndCause),
+	#[display("Cannot set metadata for path '{}'\nCause: {}", _0.path, _0.cause)]
+	CantSetMetadata(PathAndCause),
 
 	// -- Time
 	#[display("Cannot get duration from system time. Cause: {_0}")]
@@ -85,6 +118,13 @@ pub enum Error {
 		cause: globset::Error,
 	},
 
+	// -- Search
+	#[display("Cannot create regex '{regex}'.\nCause: {cause}")]
+	RegexCantNew {
+		regex: String,
+		cause: regex::Error,
+	},
+
 	// -- Watch
 	#[display("Failed to watch path '{path}'.\nCause: {cause}")]
 	FailToWatch {
@@ -118,6 +158,14 @@ pub enum Error {
 	#[cfg(feature = "with-json")]
 	#[display("Error processing NDJSON: {_0}")]
 	NdJson(String),
+	#[cfg(feature = "with-json")]
+	#[display("Cannot read NDJSON line {line} of '{path}'\nCause: {cause}")]
+	NdJsonCantReadLine {
+		path: String,
+		/// 1-based line number
+		line: usize,
+		cause: Cause,
+	},
 
 	// -- with-toml
 	#[cfg(feature = "with-toml")]
@@ -126,6 +174,57 @@ pub enum Error {
 	#[cfg(feature = "with-toml")]
 	#[display("Cannot write TOML to path '{}'\nCause: {}", _0.path, _0.cause)]
 	TomlCantWrite(PathAndCause),
+
+	// -- with-yaml
+	#[cfg(feature = "with-yaml")]
+	#[display("Cannot read YAML from path '{}'\nCause: {}", _0.path, _0.cause)]
+	YamlCantRead(PathAndCause),
+	#[cfg(feature = "with-yaml")]
+	#[display("Cannot write YAML to path '{}'\nCause: {}", _0.path, _0.cause)]
+	YamlCantWrite(PathAndCause),
+
+	// -- bin-nums
+	#[cfg(feature = "bin-nums")]
+	#[display(
+		"Binary file '{path}' ends with a partial record ({trailing} trailing bytes for a record size of {record_size})"
+	)]
+	BinPartialRecord {
+		path: String,
+		record_size: usize,
+		trailing: usize,
+	},
+	#[cfg(feature = "bin-nums")]
+	#[display("Binary value index {index} is out of bounds for '{path}' ({count} values)")]
+	BinIndexOutOfBounds {
+		path: String,
+		index: u64,
+		count: u64,
+	},
+	#[cfg(feature = "bin-nums")]
+	#[display("Invalid binary records header in '{}'\nCause: {}", _0.path, _0.cause)]
+	BinHeaderInvalid(PathAndCause),
+	#[cfg(feature = "bin-nums")]
+	#[display("Binary record does not match the schema of '{path}'\nCause: {cause}")]
+	BinRecordNotMatchingSchema {
+		path: String,
+		cause: String,
+	},
+
+	// -- with-archive
+	#[cfg(feature = "with-archive")]
+	#[display("Cannot create archive '{}'\nCause: {}", _0.path, _0.cause)]
+	ArchiveCantCreate(PathAndCause),
+	#[cfg(feature = "with-archive")]
+	#[display("Cannot extract archive '{}'\nCause: {}", _0.path, _0.cause)]
+	ArchiveCantExtract(PathAndCause),
+	#[cfg(feature = "with-archive")]
+	#[display(
+		"Archive entry '{entry}' is not safe to extract (escapes the destination or is a link)\nArchive: '{archive}'"
+	)]
+	ArchiveEntryNotSafe {
+		archive: String,
+		entry: String,
+	},
 }
 
 impl Error {
@@ -149,11 +248,23 @@ pub enum Cause {
 	#[cfg(feature = "with-json")]
 	SerdeJson(Box<serde_json::Error>),
 
+	#[cfg(feature = "with-json5")]
+	Json5(Box<json5::Error>),
+
 	#[cfg(feature = "with-toml")]
 	TomlDe(Box<toml::de::Error>),
 
 	#[cfg(feature = "with-toml")]
 	TomlSer(Box<toml::ser::Error>),
+
+	#[cfg(feature = "with-toml")]
+	TomlEdit(Box<toml_edit::TomlError>),
+
+	#[cfg(feature = "with-yaml")]
+	SerdeYaml(Box<serde_yaml::Error>),
+
+	#[cfg(feature = "with-archive")]
+	Zip(Box<zip::result::ZipError>),
 }
 
 #[derive(Debug)]
@@ -162,6 +273,14 @@ pub struct PathAndCause {
 	pub cause: Cause,
 }
 
+fn fmt_path_and_causes(items: &[PathAndCause]) -> String {
+	items
+		.iter()
+		.map(|item| format!("- '{}': {}", item.path, item.cause))
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
 // endregion: --- Cause Types
 
 // region:    --- IO
@@ -208,6 +327,16 @@ impl From<(&Path, serde_json::Error)> for PathAndCause {
 	}
 }
 
+#[cfg(feature = "with-json5")]
+impl From<(&Path, json5::Error)> for PathAndCause {
+	fn from(val: (&Path, json5::Error)) -> Self {
+		PathAndCause {
+			path: val.0.to_string_lossy().to_string(),
+			cause: Cause::Json5(Box::new(val.1)),
+		}
+	}
+}
+
 // endregion: --- JSON
 
 // region:    --- TOML
@@ -232,8 +361,46 @@ impl From<(&Path, toml::ser::Error)> for PathAndCause {
 	}
 }
 
+#[cfg(feature = "with-toml")]
+impl From<(&Path, toml_edit::TomlError)> for PathAndCause {
+	fn from(val: (&Path, toml_edit::TomlError)) -> Self {
+		PathAndCause {
+			path: val.0.to_string_lossy().to_string(),
+			cause: Cause::TomlEdit(Box::new(val.1)),
+		}
+	}
+}
+
 // endregion: --- TOML
 
+// region:    --- YAML
+
+#[cfg(feature = "with-yaml")]
+impl From<(&Path, serde_yaml::Error)> for PathAndCause {
+	fn from(val: (&Path, serde_yaml::Error)) -> Self {
+		PathAndCause {
+			path: val.0.to_string_lossy().to_string(),
+			cause: Cause::SerdeYaml(Box::new(val.1)),
+		}
+	}
+}
+
+// endregion: --- YAML
+
+// region:    --- Archive
+
+#[cfg(feature = "with-archive")]
+impl From<(&Path, zip::result::ZipError)> for PathAndCause {
+	fn from(val: (&Path, zip::result::ZipError)) -> Self {
+		PathAndCause {
+			path: val.0.to_string_lossy().to_string(),
+			cause: Cause::Zip(Box::new(val.1)),
+		}
+	}
+}
+
+// endregion: --- Archive
+
 // region:    --- Error Boilerplate
 
 impl std::error::Error for Error {}
@@ -0,0 +1,200 @@
+use crate::{Error, Result, SPath};
+use std::path::Path;
+
+/// Loads the file with the format matching its extension (case insensitive):
+///
+/// - `json`, `jsonc` (requires `with-json`), `jsonc` is loaded with `load_jsonc`
+/// - `json5` (requires `with-json5`)
+/// - `ndjson`, `jsonl` (requires `with-json`), the lines are deserialized as an array (e.g., `Vec<T>`)
+/// - `toml` (requires `with-toml`)
+/// - `yaml`, `yml` (requires `with-yaml`)
+///
+/// Returns `Error::FileFormatNotSupported` for other extensions,
+/// and `Error::FileFormatFeatureNotEnabled` when the format's feature is not enabled.
+pub fn load_auto<T>(file_path: impl AsRef<Path>) -> Result<T>
+where
+	T: serde::de::DeserializeOwned,
+{
+	let file_path = file_path.as_ref();
+	let format = AutoFormat::from_path(file_path)?;
+
+	match format {
+		#[cfg(feature = "with-json")]
+		AutoFormat::Json => crate::load_json(file_path),
+		#[cfg(feature = "with-json")]
+		AutoFormat::Jsonc => crate::load_jsonc(file_path),
+		#[cfg(feature = "with-json5")]
+		AutoFormat::Json5 => crate::load_json5(file_path),
+		#[cfg(feature = "with-json")]
+		AutoFormat::Ndjson => {
+			let values = crate::load_ndjson(file_path)?;
+			serde_json::from_value(serde_json::Value::Array(values))
+				.map_err(|e| Error::JsonCantRead((file_path, e).into()))
+		}
+		#[cfg(feature = "with-toml")]
+		AutoFormat::Toml => crate::load_toml(file_path),
+		#[cfg(feature = "with-yaml")]
+		AutoFormat::Yaml => crate::load_yaml(file_path),
+		#[allow(unreachable_patterns)]
+		_ => Err(format.feature_not_enabled_error(file_path)),
+	}
+}
+
+/// Saves the data with the format matching the file extension (see [`load_auto`] for the formats).
+///
+/// For `ndjson` / `jsonl`, if the data serializes to an array, each item is written as one line,
+/// otherwise the data is written as a single line.
+///
+/// Note: `json` is saved pretty, and `jsonc` / `json5` are saved as plain (pretty) json (comments are not preserved).
+pub fn save_auto<T>(file_path: impl AsRef<Path>, data: &T) -> Result<()>
+where
+	T: serde::Serialize,
+{
+	let file_path = file_path.as_ref();
+	let format = AutoFormat::from_path(file_path)?;
+
+	match format {
+		#[cfg(feature = "with-json")]
+		AutoFormat::Json | AutoFormat::Jsonc => crate::save_json_pretty(file_path, data),
+		#[cfg(feature = "with-json5")]
+		AutoFormat::Json5 => crate::save_json_pretty(file_path, data),
+		#[cfg(feature = "with-json")]
+		AutoFormat::Ndjson => save_ndjson_auto(file_path, data),
+		#[cfg(feature = "with-toml")]
+		AutoFormat::Toml => crate::save_toml(file_path, data),
+		#[cfg(feature = "with-yaml")]
+		AutoFormat::Yaml => crate::save_yaml(file_path, data),
+		#[allow(unreachable_patterns)]
+		_ => Err(format.feature_not_enabled_error(file_path)),
+	}
+}
+
+// region:    --- AutoFormat
+
+#[derive(Debug, Clone, Copy)]
+enum AutoFormat {
+	Json,
+	Jsonc,
+	Json5,
+	Ndjson,
+	Toml,
+	Yaml,
+}
+
+impl AutoFormat {
+	fn from_path(file_path: &Path) -> Result<Self> {
+		let spath = SPath::from_std_path(file_path)?;
+		let ext = spath.ext().to_ascii_lowercase();
+
+		let format = match ext.as_str() {
+			"json" => Self::Json,
+			"jsonc" => Self::Jsonc,
+			"json5" => Self::Json5,
+			"ndjson" | "jsonl" => Self::Ndjson,
+			"toml" => Self::Toml,
+			"yaml" | "yml" => Self::Yaml,
+			_ => {
+				return Err(Error::FileFormatNotSupported {
+					path: spath.to_string(),
+					ext,
+				});
+			}
+		};
+
+		Ok(format)
+	}
+
+	fn feature_name(&self) -> &'static str {
+		match self {
+			Self::Json | Self::Jsonc | Self::Ndjson => "with-json",
+			Self::Json5 => "with-json5",
+			Self::Toml => "with-toml",
+			Self::Yaml => "with-yaml",
+		}
+	}
+
+	fn feature_not_enabled_error(&self, file_path: &Path) -> Error {
+		let path = file_path.to_string_lossy().to_string();
+		let ext = SPath::from_std_path_ok(file_path)
+			.map(|p| p.ext().to_string())
+			.unwrap_or_default();
+		Error::FileFormatFeatureNotEnabled {
+			path,
+			ext,
+			feature: self.feature_name(),
+		}
+	}
+}
+
+// endregion: --- AutoFormat
+
+// region:    --- Support
+
+#[cfg(feature = "with-json")]
+fn save_ndjson_auto<T>(file_path: &Path, data: &T) -> Result<()>
+where
+	T: serde::Serialize,
+{
+	use std::io::Write;
+
+	let value = serde_json::to_value(data).map_err(|e| Error::JsonCantWrite((file_path, e).into()))?;
+	let values = match value {
+		serde_json::Value::Array(values) => values,
+		other => vec![other],
+	};
+
+	let mut writer = crate::get_buf_writer(file_path)?;
+	for value in values {
+		serde_json::to_writer(&mut writer, &value).map_err(|e| Error::JsonCantWrite((file_path, e).into()))?;
+		writeln!(writer).map_err(|e| Error::FileCantWrite((file_path, e).into()))?;
+	}
+	writer.flush().map_err(|e| Error::FileCantWrite((file_path, e).into()))?;
+
+	Ok(())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_featured_auto_unsupported_ext() -> Result<()> {
+		// -- Exec
+		let res = load_auto::<String>("target/tests-tmp/auto/config.ini");
+
+		// -- Check
+		let Err(Error::FileFormatNotSupported { ext, .. }) = res else {
+			return Err("should be FileFormatNotSupported".into());
+		};
+		assert_eq!(ext, "ini");
+
+		Ok(())
+	}
+
+	#[cfg(feature = "with-json")]
+	#[test]
+	fn test_featured_auto_ndjson_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/auto/events.JSONL");
+		crate::ensure_file_dir(file)?;
+		let data = vec![serde_json::json!({"id": 1}), serde_json::json!({"id": 2})];
+
+		// -- Exec
+		save_auto(file, &data)?;
+		let loaded: Vec<serde_json::Value> = load_auto(file)?;
+
+		// -- Check
+		assert_eq!(std::fs::read_to_string(file)?, "{\"id\":1}\n{\"id\":2}\n");
+		assert_eq!(loaded, data);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,85 @@
+use byteorder::ByteOrder;
+use half::f16;
+
+/// A number type which can be stored in the flat binary files of `bin-nums`
+/// (used by the generic `load_nums::<T, E>`, `save_nums::<T, E>`, ... functions).
+///
+/// `E` is the byte order, `byteorder::BigEndian` or `byteorder::LittleEndian`
+/// (re-exported as `simple_fs::BigEndian` and `simple_fs::LittleEndian`).
+pub trait BinNum: Copy + Default + 'static {
+	/// Converts `src` bytes into `dst` values (`src.len()` must be `dst.len() * size_of::<Self>()`).
+	fn read_into<E: ByteOrder>(src: &[u8], dst: &mut [Self]);
+
+	/// Converts `src` values into `dst` bytes (`dst.len()` must be `src.len() * size_of::<Self>()`).
+	fn write_into<E: ByteOrder>(src: &[Self], dst: &mut [u8]);
+}
+
+macro_rules! impl_bin_num_byteorder {
+	( $( $type:ty, $byteorder_read_into_fn:ident, $byteorder_write_into_fn:ident );* $(;)? ) => {
+		$(
+			impl BinNum for $type {
+				fn read_into<E: ByteOrder>(src: &[u8], dst: &mut [Self]) {
+					E::$byteorder_read_into_fn(src, dst)
+				}
+
+				fn write_into<E: ByteOrder>(src: &[Self], dst: &mut [u8]) {
+					E::$byteorder_write_into_fn(src, dst)
+				}
+			}
+		)*
+	};
+}
+
+impl_bin_num_byteorder!(
+	f64, read_f64_into, write_f64_into;
+	f32, read_f32_into, write_f32_into;
+	u128, read_u128_into, write_u128_into;
+	u64, read_u64_into, write_u64_into;
+	u32, read_u32_into, write_u32_into;
+	u16, read_u16_into, write_u16_into;
+	i128, read_i128_into, write_i128_into;
+	i64, read_i64_into, write_i64_into;
+	i32, read_i32_into, write_i32_into;
+	i16, read_i16_into, write_i16_into;
+);
+
+// NOTE: Single byte types have no byte order.
+
+impl BinNum for u8 {
+	fn read_into<E: ByteOrder>(src: &[u8], dst: &mut [Self]) {
+		dst.copy_from_slice(src);
+	}
+
+	fn write_into<E: ByteOrder>(src: &[Self], dst: &mut [u8]) {
+		dst.copy_from_slice(src);
+	}
+}
+
+impl BinNum for i8 {
+	fn read_into<E: ByteOrder>(src: &[u8], dst: &mut [Self]) {
+		for (d, s) in dst.iter_mut().zip(src) {
+			*d = *s as i8;
+		}
+	}
+
+	fn write_into<E: ByteOrder>(src: &[Self], dst: &mut [u8]) {
+		for (d, s) in dst.iter_mut().zip(src) {
+			*d = *s as u8;
+		}
+	}
+}
+
+/// Half precision float (IEEE 754 binary16), stored as its 16 bits.
+impl BinNum for f16 {
+	fn read_into<E: ByteOrder>(src: &[u8], dst: &mut [Self]) {
+		for (d, s) in dst.iter_mut().zip(src.chunks_exact(2)) {
+			*d = f16::from_bits(E::read_u16(s));
+		}
+	}
+
+	fn write_into<E: ByteOrder>(src: &[Self], dst: &mut [u8]) {
+		for (s, d) in src.iter().zip(dst.chunks_exact_mut(2)) {
+			E::write_u16(d, s.to_bits());
+		}
+	}
+}
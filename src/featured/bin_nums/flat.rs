@@ -0,0 +1,189 @@
+use super::support::write_nums;
+use super::{BinNum, BinNumIter};
+use crate::get_buf_writer;
+use crate::{Error, Result};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use half::f16;
+use std::io::Write;
+use std::path::Path;
+
+// region:    --- Generic Load & Save
+
+/// Loads all the `T` numbers of a flat binary file with the `E` byte order
+/// (e.g., `load_nums::<f32, LittleEndian>(path)`).
+///
+/// Values are read and converted by chunks, and a partial trailing value is an `Error::BinPartialRecord`.
+pub fn load_nums<T: BinNum, E: ByteOrder>(file_path: impl AsRef<Path>) -> Result<Vec<T>> {
+	BinNumIter::open(file_path.as_ref(), T::read_into::<E>)?.into_vec()
+}
+
+/// Saves the `T` numbers to a flat binary file with the `E` byte order (the file is created or truncated).
+pub fn save_nums<T: BinNum, E: ByteOrder>(file_path: impl AsRef<Path>, data: &[T]) -> Result<()> {
+	let file_path = file_path.as_ref();
+	let mut writer = get_buf_writer(file_path)?;
+
+	write_nums(&mut writer, data, T::write_into::<E>, file_path)?;
+
+	writer.flush().map_err(|e| Error::FileCantWrite((file_path, e).into()))?;
+	Ok(())
+}
+
+// endregion: --- Generic Load & Save
+
+// region:    --- Loaders
+
+macro_rules! generate_load_functions {
+	( $( $type:ty, $load_be_fn_name:ident, $load_le_fn_name:ident );* $(;)? ) => {
+		$(
+			pub fn $load_be_fn_name(file_path: impl AsRef<Path>) -> Result<Vec<$type>> {
+				load_nums::<$type, BigEndian>(file_path)
+			}
+
+			pub fn $load_le_fn_name(file_path: impl AsRef<Path>) -> Result<Vec<$type>> {
+				load_nums::<$type, LittleEndian>(file_path)
+			}
+		)*
+	};
+}
+
+generate_load_functions!(
+	f64, load_be_f64, load_le_f64;
+	f32, load_be_f32, load_le_f32;
+	f16, load_be_f16, load_le_f16;
+	u128, load_be_u128, load_le_u128;
+	u64, load_be_u64, load_le_u64;
+	u32, load_be_u32, load_le_u32;
+	u16, load_be_u16, load_le_u16;
+	i128, load_be_i128, load_le_i128;
+	i64, load_be_i64, load_le_i64;
+	i32, load_be_i32, load_le_i32;
+	i16, load_be_i16, load_le_i16;
+);
+
+// NOTE: Single byte types have no byte order, so they have a single loader.
+macro_rules! generate_byte_load_functions {
+	( $( $type:ty, $load_fn_name:ident );* $(;)? ) => {
+		$(
+			pub fn $load_fn_name(file_path: impl AsRef<Path>) -> Result<Vec<$type>> {
+				load_nums::<$type, LittleEndian>(file_path)
+			}
+		)*
+	};
+}
+
+generate_byte_load_functions!(
+	u8, load_u8;
+	i8, load_i8;
+);
+
+// endregion: --- Loaders
+
+// region:    --- Savers
+
+macro_rules! generate_save_functions {
+	( $( $type:ty, $save_be_fn_name:ident, $save_le_fn_name:ident );* $(;)? ) => {
+		$(
+			pub fn $save_be_fn_name(file_path: impl AsRef<Path>, data: &[$type]) -> Result<()> {
+				save_nums::<$type, BigEndian>(file_path, data)
+			}
+
+			pub fn $save_le_fn_name(file_path: impl AsRef<Path>, data: &[$type]) -> Result<()> {
+				save_nums::<$type, LittleEndian>(file_path, data)
+			}
+		)*
+	};
+}
+
+generate_save_functions!(
+	f64, save_be_f64, save_le_f64;
+	f32, save_be_f32, save_le_f32;
+	f16, save_be_f16, save_le_f16;
+	u128, save_be_u128, save_le_u128;
+	u64, save_be_u64, save_le_u64;
+	u32, save_be_u32, save_le_u32;
+	u16, save_be_u16, save_le_u16;
+	i128, save_be_i128, save_le_i128;
+	i64, save_be_i64, save_le_i64;
+	i32, save_be_i32, save_le_i32;
+	i16, save_be_i16, save_le_i16;
+);
+
+// NOTE: Single byte types have no byte order, so they have a single saver.
+macro_rules! generate_byte_save_functions {
+	( $( $type:ty, $save_fn_name:ident );* $(;)? ) => {
+		$(
+			pub fn $save_fn_name(file_path: impl AsRef<Path>, data: &[$type]) -> Result<()> {
+				save_nums::<$type, LittleEndian>(file_path, data)
+			}
+		)*
+	};
+}
+
+generate_byte_save_functions!(
+	u8, save_u8;
+	i8, save_i8;
+);
+
+// endregion: --- Savers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_file_dir;
+	use std::fs;
+
+	#[test]
+	fn test_featured_bin_nums_load_partial_trailing() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/bin-nums-partial/values.bin");
+		ensure_file_dir(file)?;
+		save_le_u32(file, &[1, 2])?;
+		let mut content = fs::read(file)?;
+		content.extend_from_slice(&[0, 0, 0]);
+		fs::write(file, content)?;
+
+		// -- Exec
+		let res = load_le_u32(file);
+
+		// -- Check
+		assert!(matches!(
+			res,
+			Err(Error::BinPartialRecord {
+				record_size: 4,
+				trailing: 3,
+				..
+			})
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_bin_nums_generic_and_new_types() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/bin-nums-generic");
+		crate::ensure_dir(dir)?;
+		let halfs = [f16::from_f32(1.5), f16::from_f32(-0.25)];
+		let bigs = [u128::MAX, 1];
+		let small = [-128i8, 0, 127];
+
+		// -- Exec
+		save_be_f16(dir.join("half.bin"), &halfs)?;
+		save_nums::<u128, LittleEndian>(dir.join("u128.bin"), &bigs)?;
+		save_i8(dir.join("i8.bin"), &small)?;
+
+		// -- Check
+		assert_eq!(fs::read(dir.join("half.bin"))?.len(), 4);
+		assert_eq!(load_nums::<f16, BigEndian>(dir.join("half.bin"))?, halfs);
+		assert_eq!(load_le_u128(dir.join("u128.bin"))?, bigs);
+		assert_eq!(load_nums::<i8, BigEndian>(dir.join("i8.bin"))?, small);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,18 @@
+// region:    --- Modules
+
+mod bin_num;
+mod flat;
+mod records;
+mod stream;
+mod support;
+
+pub use bin_num::*;
+pub use flat::*;
+pub use records::*;
+pub use stream::*;
+
+// -- Re-export the byte order and half float types used by the bin-nums API
+pub use byteorder::{BigEndian, LittleEndian};
+pub use half::f16;
+
+// endregion: --- Modules
@@ -0,0 +1,606 @@
+use super::BinNum;
+use super::support::read_record;
+use crate::error::{Cause, PathAndCause};
+use crate::{Error, Result, create_file, open_file};
+use byteorder::ByteOrder;
+use half::f16;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+const BIN_RECORDS_MAGIC: &[u8; 4] = b"SFBR";
+const BIN_RECORDS_VERSION: u8 = 1;
+/// Offset of the record count in the header (after magic, version, endian, field count, and fields)
+const fn count_offset(field_count: usize) -> u64 {
+	(4 + 1 + 1 + 2 + field_count) as u64
+}
+
+// region:    --- Types
+
+/// The numeric types of the binary record fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumType {
+	U8,
+	U16,
+	U32,
+	U64,
+	U128,
+	I8,
+	I16,
+	I32,
+	I64,
+	I128,
+	F16,
+	F32,
+	F64,
+}
+
+/// A numeric value of a binary record field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumValue {
+	U8(u8),
+	U16(u16),
+	U32(u32),
+	U64(u64),
+	U128(u128),
+	I8(i8),
+	I16(i16),
+	I32(i32),
+	I64(i64),
+	I128(i128),
+	F16(f16),
+	F32(f32),
+	F64(f64),
+}
+
+/// The header of a binary records file.
+///
+/// Layout (little endian): magic `SFBR` (4 bytes), version (u8), byte order of the values (u8, 0 little / 1 big),
+/// field count (u16), field types (one u8 per field), record count (u64).
+///
+/// Note: The byte order of the values is the `E: ByteOrder` type parameter of the writer and reader
+///       (`BigEndian` or `LittleEndian`), the reader fails when it does not match the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinRecordHeader {
+	pub version: u8,
+	pub fields: Vec<NumType>,
+	pub count: u64,
+}
+
+// endregion: --- Types
+
+// region:    --- Public Functions
+
+/// Saves the records with the `E` byte order to a binary records file (see [`BinRecordHeader`] for the layout),
+/// e.g., `save_bin_records::<LittleEndian>(path, &fields, &records)`.
+///
+/// Each record must match `fields` (same count and types), otherwise an `Error::BinRecordNotMatchingSchema` is returned.
+pub fn save_bin_records<E: ByteOrder>(
+	file_path: impl AsRef<Path>,
+	fields: &[NumType],
+	records: impl IntoIterator<Item: AsRef<[NumValue]>>,
+) -> Result<u64> {
+	let mut writer = BinRecordWriter::<E>::create(file_path, fields)?;
+	for record in records {
+		writer.write_record(record.as_ref())?;
+	}
+	writer.finish()
+}
+
+/// Loads all the records of a binary records file with the `E` byte order.
+pub fn load_bin_records<E: ByteOrder>(file_path: impl AsRef<Path>) -> Result<Vec<Vec<NumValue>>> {
+	iter_bin_records::<E>(file_path)?.collect()
+}
+
+/// Returns a streaming reader (iterator of `Result<Vec<NumValue>>`) over the records of a binary records file
+/// with the `E` byte order.
+///
+/// The header and the file size are validated when opening (see [`BinRecordReader::open`]).
+pub fn iter_bin_records<E: ByteOrder>(file_path: impl AsRef<Path>) -> Result<BinRecordReader<E>> {
+	BinRecordReader::open(file_path)
+}
+
+// endregion: --- Public Functions
+
+// region:    --- BinRecordWriter
+
+/// Writes the records one by one, the header count is written by [`BinRecordWriter::finish`].
+///
+/// Note: If not finished, the header count stays 0 and the file will fail to open with `BinRecordReader`.
+#[derive(Debug)]
+pub struct BinRecordWriter<E: ByteOrder> {
+	path: PathBuf,
+	writer: BufWriter<File>,
+	fields: Vec<NumType>,
+	count: u64,
+	buf: Vec<u8>,
+	_byte_order: PhantomData<E>,
+}
+
+impl<E: ByteOrder> BinRecordWriter<E> {
+	pub fn create(file_path: impl AsRef<Path>, fields: &[NumType]) -> Result<Self> {
+		let path = file_path.as_ref().to_path_buf();
+
+		if fields.is_empty() || fields.len() > u16::MAX as usize {
+			return Err(schema_err(
+				&path,
+				format!("field count must be 1 to 65535 (was {})", fields.len()),
+			));
+		}
+
+		let header = BinRecordHeader {
+			version: BIN_RECORDS_VERSION,
+			fields: fields.to_vec(),
+			count: 0,
+		};
+
+		let mut writer = BufWriter::new(create_file(&path)?);
+		writer
+			.write_all(&header.to_bytes::<E>())
+			.map_err(|e| Error::FileCantWrite((path.as_path(), e).into()))?;
+
+		Ok(Self {
+			buf: vec![0; header.record_size()],
+			path,
+			writer,
+			fields: header.fields,
+			count: 0,
+			_byte_order: PhantomData,
+		})
+	}
+
+	pub fn write_record(&mut self, record: &[NumValue]) -> Result<()> {
+		if record.len() != self.fields.len() {
+			return Err(schema_err(
+				&self.path,
+				format!("record has {} values, expected {}", record.len(), self.fields.len()),
+			));
+		}
+
+		let mut offset = 0;
+		for (idx, (value, num_type)) in record.iter().zip(&self.fields).enumerate() {
+			if value.num_type() != *num_type {
+				return Err(schema_err(
+					&self.path,
+					format!("value #{idx} is {:?}, expected {num_type:?}", value.num_type()),
+				));
+			}
+			let size = num_type.size();
+			value.write::<E>(&mut self.buf[offset..offset + size]);
+			offset += size;
+		}
+
+		self.writer
+			.write_all(&self.buf)
+			.map_err(|e| Error::FileCantWrite((self.path.as_path(), e).into()))?;
+		self.count += 1;
+
+		Ok(())
+	}
+
+	/// Writes the record count in the header and flushes the file.
+	/// Returns the number of records written.
+	pub fn finish(mut self) -> Result<u64> {
+		let path = self.path.as_path();
+		let write_err = |e| Error::FileCantWrite((path, e).into());
+
+		self.writer.flush().map_err(write_err)?;
+		let file = self.writer.get_mut();
+		file.seek(SeekFrom::Start(count_offset(self.fields.len()))).map_err(write_err)?;
+		file.write_all(&self.count.to_le_bytes()).map_err(write_err)?;
+		file.flush().map_err(write_err)?;
+
+		Ok(self.count)
+	}
+}
+
+// endregion: --- BinRecordWriter
+
+// region:    --- BinRecordReader
+
+/// Streaming reader of a binary records file, an iterator of `Result<Vec<NumValue>>`.
+#[derive(Debug)]
+pub struct BinRecordReader<E: ByteOrder> {
+	path: PathBuf,
+	reader: BufReader<File>,
+	header: BinRecordHeader,
+	remaining: u64,
+	buf: Vec<u8>,
+	_byte_order: PhantomData<E>,
+}
+
+impl<E: ByteOrder> BinRecordReader<E> {
+	/// Opens the file and reads its header.
+	///
+	/// Fails with:
+	/// - `Error::BinHeaderInvalid` when the magic, version, byte order (not `E`), or types are invalid,
+	///   or when the header count does not match the data size,
+	/// - `Error::BinPartialRecord` when the data ends with a partial record.
+	pub fn open(file_path: impl AsRef<Path>) -> Result<Self> {
+		let path = file_path.as_ref().to_path_buf();
+		let file = open_file(&path)?;
+		let file_len = file
+			.metadata()
+			.map_err(|e| Error::CantGetMetadata((path.as_path(), e).into()))?
+			.len();
+
+		let mut reader = BufReader::new(file);
+		let header = BinRecordHeader::read_from::<E>(&mut reader, &path)?;
+
+		// -- Validate the data size
+		let record_size = header.record_size() as u64;
+		let data_len = file_len.saturating_sub(count_offset(header.fields.len()) + 8);
+		let trailing = data_len % record_size;
+		if trailing != 0 {
+			return Err(Error::BinPartialRecord {
+				path: path.to_string_lossy().to_string(),
+				record_size: record_size as usize,
+				trailing: trailing as usize,
+			});
+		}
+		let data_count = data_len / record_size;
+		if data_count != header.count {
+			return Err(header_err(
+				&path,
+				format!("header count is {} but the file has {data_count} records", header.count),
+			));
+		}
+
+		Ok(Self {
+			buf: vec![0; record_size as usize],
+			remaining: header.count,
+			path,
+			reader,
+			header,
+			_byte_order: PhantomData,
+		})
+	}
+
+	pub fn header(&self) -> &BinRecordHeader {
+		&self.header
+	}
+
+	fn read_next(&mut self) -> Result<Option<Vec<NumValue>>> {
+		if !read_record(&mut self.reader, &mut self.buf, &self.path)? {
+			return Ok(None);
+		}
+
+		let mut offset = 0;
+		let mut record = Vec::with_capacity(self.header.fields.len());
+		for num_type in &self.header.fields {
+			let size = num_type.size();
+			record.push(num_type.read::<E>(&self.buf[offset..offset + size]));
+			offset += size;
+		}
+
+		Ok(Some(record))
+	}
+}
+
+impl<E: ByteOrder> Iterator for BinRecordReader<E> {
+	type Item = Result<Vec<NumValue>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+
+		match self.read_next() {
+			Ok(Some(record)) => Some(Ok(record)),
+			Ok(None) => {
+				self.remaining = 0;
+				Some(Err(Error::BinPartialRecord {
+					path: self.path.to_string_lossy().to_string(),
+					record_size: self.buf.len(),
+					trailing: 0,
+				}))
+			}
+			Err(err) => {
+				self.remaining = 0;
+				Some(Err(err))
+			}
+		}
+	}
+}
+
+// endregion: --- BinRecordReader
+
+// region:    --- BinRecordHeader
+
+impl BinRecordHeader {
+	/// The size in bytes of one record.
+	pub fn record_size(&self) -> usize {
+		self.fields.iter().map(|f| f.size()).sum()
+	}
+
+	fn to_bytes<E: ByteOrder>(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(count_offset(self.fields.len()) as usize + 8);
+		bytes.extend_from_slice(BIN_RECORDS_MAGIC);
+		bytes.push(self.version);
+		bytes.push(byte_order_code::<E>());
+		bytes.extend_from_slice(&(self.fields.len() as u16).to_le_bytes());
+		bytes.extend(self.fields.iter().map(|f| f.code()));
+		bytes.extend_from_slice(&self.count.to_le_bytes());
+		bytes
+	}
+
+	fn read_from<E: ByteOrder>(reader: &mut impl Read, path: &Path) -> Result<Self> {
+		let mut read_exact = |buf: &mut [u8]| {
+			reader
+				.read_exact(buf)
+				.map_err(|e| header_err(path, format!("cannot read header ({e})")))
+		};
+
+		let mut head = [0u8; 8];
+		read_exact(&mut head)?;
+		if &head[..4] != BIN_RECORDS_MAGIC {
+			return Err(header_err(path, "wrong magic bytes (not a binary records file)"));
+		}
+
+		let version = head[4];
+		if version != BIN_RECORDS_VERSION {
+			return Err(header_err(path, format!("unsupported version {version}")));
+		}
+
+		let expected_code = byte_order_code::<E>();
+		if head[5] != expected_code {
+			let cause = match head[5] {
+				0 | 1 => format!(
+					"values are {}, expected {}",
+					byte_order_name(head[5]),
+					byte_order_name(expected_code)
+				),
+				other => format!("invalid byte order {other}"),
+			};
+			return Err(header_err(path, cause));
+		}
+
+		let field_count = u16::from_le_bytes([head[6], head[7]]) as usize;
+		if field_count == 0 {
+			return Err(header_err(path, "no fields"));
+		}
+		let mut codes = vec![0u8; field_count];
+		read_exact(&mut codes)?;
+		let fields = codes
+			.into_iter()
+			.map(|code| NumType::from_code(code).ok_or_else(|| header_err(path, format!("invalid field type {code}"))))
+			.collect::<Result<Vec<_>>>()?;
+
+		let mut count = [0u8; 8];
+		read_exact(&mut count)?;
+
+		Ok(Self {
+			version,
+			fields,
+			count: u64::from_le_bytes(count),
+		})
+	}
+}
+
+// endregion: --- BinRecordHeader
+
+// region:    --- NumType & NumValue
+
+/// Defines the NumType / NumValue mapping (header code, conversion to `f64`),
+/// the values being read and written with their [`BinNum`] implementation.
+macro_rules! impl_num_types {
+	( $( $variant:ident, $type:ty, $code:expr, $to_f64:expr );* $(;)? ) => {
+		impl NumType {
+			/// The size in bytes of the type.
+			pub fn size(self) -> usize {
+				match self {
+					$( NumType::$variant => size_of::<$type>(), )*
+				}
+			}
+
+			fn code(self) -> u8 {
+				match self {
+					$( NumType::$variant => $code, )*
+				}
+			}
+
+			fn from_code(code: u8) -> Option<Self> {
+				match code {
+					$( $code => Some(NumType::$variant), )*
+					_ => None,
+				}
+			}
+
+			fn read<E: ByteOrder>(self, buf: &[u8]) -> NumValue {
+				match self {
+					$(
+						NumType::$variant => {
+							let mut value = [<$type>::default()];
+							<$type>::read_into::<E>(buf, &mut value);
+							NumValue::$variant(value[0])
+						}
+					)*
+				}
+			}
+		}
+
+		impl NumValue {
+			pub fn num_type(&self) -> NumType {
+				match self {
+					$( NumValue::$variant(_) => NumType::$variant, )*
+				}
+			}
+
+			/// Returns the value as `f64` (can lose precision for large 64 and 128 bits integers).
+			pub fn as_f64(&self) -> f64 {
+				match *self {
+					$( NumValue::$variant(v) => ($to_f64)(v), )*
+				}
+			}
+
+			fn write<E: ByteOrder>(&self, buf: &mut [u8]) {
+				match *self {
+					$( NumValue::$variant(v) => <$type>::write_into::<E>(&[v], buf), )*
+				}
+			}
+		}
+
+		$(
+			impl From<$type> for NumValue {
+				fn from(val: $type) -> Self {
+					NumValue::$variant(val)
+				}
+			}
+		)*
+	};
+}
+
+// NOTE: The codes are stored in the file header, so existing codes must not change.
+impl_num_types!(
+	U16, u16, 1, |v: u16| v as f64;
+	U32, u32, 2, |v: u32| v as f64;
+	U64, u64, 3, |v: u64| v as f64;
+	I16, i16, 4, |v: i16| v as f64;
+	I32, i32, 5, |v: i32| v as f64;
+	I64, i64, 6, |v: i64| v as f64;
+	F32, f32, 7, |v: f32| v as f64;
+	F64, f64, 8, |v: f64| v;
+	U8, u8, 9, |v: u8| v as f64;
+	I8, i8, 10, |v: i8| v as f64;
+	U128, u128, 11, |v: u128| v as f64;
+	I128, i128, 12, |v: i128| v as f64;
+	F16, f16, 13, |v: f16| v.to_f64();
+);
+
+// endregion: --- NumType & NumValue
+
+// region:    --- Support
+
+/// The header code of the `E` byte order (0 little endian, 1 big endian).
+fn byte_order_code<E: ByteOrder>() -> u8 {
+	let mut buf = [0u8; 2];
+	E::write_u16(&mut buf, 1);
+	if buf[0] == 1 { 0 } else { 1 }
+}
+
+fn byte_order_name(code: u8) -> &'static str {
+	if code == 0 { "little endian" } else { "big endian" }
+}
+
+fn header_err(path: &Path, cause: impl Into<String>) -> Error {
+	Error::BinHeaderInvalid(PathAndCause {
+		path: path.to_string_lossy().to_string(),
+		cause: Cause::Custom(cause.into()),
+	})
+}
+
+fn schema_err(path: &Path, cause: String) -> Error {
+	Error::BinRecordNotMatchingSchema {
+		path: path.to_string_lossy().to_string(),
+		cause,
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_file_dir;
+	use byteorder::{BigEndian, LittleEndian};
+	use std::fs::{self, OpenOptions};
+
+	#[test]
+	fn test_featured_bin_records_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/bin-records/samples.bin");
+		ensure_file_dir(file)?;
+		let fields = [NumType::U32, NumType::F64, NumType::I16];
+		let records: Vec<Vec<NumValue>> = (0..10u32)
+			.map(|i| vec![i.into(), (i as f64 * 1.5).into(), (-(i as i16)).into()])
+			.collect();
+
+		// -- Exec
+		let count = save_bin_records::<BigEndian>(file, &fields, &records)?;
+		let reader = iter_bin_records::<BigEndian>(file)?;
+		let header = reader.header().clone();
+		let loaded = reader.collect::<crate::Result<Vec<_>>>()?;
+		let other_order_res = iter_bin_records::<LittleEndian>(file);
+
+		// -- Check
+		assert_eq!(count, 10);
+		assert_eq!(header.fields, fields);
+		assert_eq!(header.count, 10);
+		assert_eq!(header.record_size(), 14);
+		assert_eq!(loaded, records);
+		assert!(matches!(other_order_res, Err(Error::BinHeaderInvalid(_))));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_bin_records_all_types() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/bin-records-all-types/samples.bin");
+		ensure_file_dir(file)?;
+		let record = vec![
+			NumValue::U8(200),
+			NumValue::U16(60_000),
+			NumValue::U32(4_000_000_000),
+			NumValue::U64(u64::MAX),
+			NumValue::U128(u128::MAX - 1),
+			NumValue::I8(-100),
+			NumValue::I16(-30_000),
+			NumValue::I32(-2_000_000_000),
+			NumValue::I64(i64::MIN),
+			NumValue::I128(i128::MIN + 1),
+			NumValue::F16(f16::from_f32(1.5)),
+			NumValue::F32(-0.25),
+			NumValue::F64(1e300),
+		];
+		let fields: Vec<NumType> = record.iter().map(|v| v.num_type()).collect();
+
+		// -- Exec
+		save_bin_records::<LittleEndian>(file, &fields, [&record])?;
+		let reader = iter_bin_records::<LittleEndian>(file)?;
+		let record_size = reader.header().record_size();
+		let loaded = reader.collect::<crate::Result<Vec<_>>>()?;
+
+		// -- Check
+		assert_eq!(record_size, 1 + 2 + 4 + 8 + 16 + 1 + 2 + 4 + 8 + 16 + 2 + 4 + 8);
+		assert_eq!(loaded, [record]);
+		assert_eq!(loaded[0][10].as_f64(), 1.5);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_bin_records_partial_and_schema_errors() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/bin-records-err/samples.bin");
+		ensure_file_dir(file)?;
+		save_bin_records::<LittleEndian>(file, &[NumType::U16], [[NumValue::U16(1)], [NumValue::U16(2)]])?;
+		OpenOptions::new().append(true).open(file)?.write_all(&[0xFF])?;
+
+		// -- Exec
+		let partial_res = iter_bin_records::<LittleEndian>(file);
+		let schema_res = save_bin_records::<LittleEndian>(file, &[NumType::U16], [[NumValue::F32(1.0)]]);
+
+		// -- Check
+		assert!(matches!(
+			partial_res,
+			Err(Error::BinPartialRecord {
+				record_size: 2,
+				trailing: 1,
+				..
+			})
+		));
+		assert!(matches!(schema_res, Err(Error::BinRecordNotMatchingSchema { .. })));
+		fs::remove_file(file)?;
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,346 @@
+use super::BinNum;
+use super::support::{BIN_NUMS_CHUNK_BYTES, write_nums};
+use crate::{Error, Result, open_file, read_exact_at};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use half::f16;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+
+// region:    --- BinNumIter
+
+/// Streaming iterator over the numbers of a flat binary file (e.g., from `iter_le_f32`),
+/// reading and converting chunks of values at once.
+///
+/// A partial trailing value yields a final `Error::BinPartialRecord`.
+pub struct BinNumIter<T> {
+	path: PathBuf,
+	file: File,
+	read_into: fn(&[u8], &mut [T]),
+	bytes: Vec<u8>,
+	values: Vec<T>,
+	pos: usize,
+	pending_err: Option<Error>,
+	eof: bool,
+}
+
+impl<T: Copy + Default> BinNumIter<T> {
+	pub(super) fn open(file_path: &Path, read_into: fn(&[u8], &mut [T])) -> Result<Self> {
+		let size = size_of::<T>();
+		Ok(Self {
+			path: file_path.to_path_buf(),
+			file: open_file(file_path)?,
+			read_into,
+			// NOTE: Chunk size rounded down to a multiple of the value size
+			bytes: vec![0; BIN_NUMS_CHUNK_BYTES / size * size],
+			values: Vec::new(),
+			pos: 0,
+			pending_err: None,
+			eof: false,
+		})
+	}
+
+	/// Loads all the remaining values.
+	pub(super) fn into_vec(mut self) -> Result<Vec<T>> {
+		let mut data = Vec::new();
+		while self.fill_chunk()? {
+			data.extend_from_slice(&self.values);
+		}
+		match self.pending_err.take() {
+			Some(err) => Err(err),
+			None => Ok(data),
+		}
+	}
+
+	/// Reads and converts the next chunk of values into `self.values`.
+	/// Returns `Ok(false)` when there are no more values.
+	fn fill_chunk(&mut self) -> Result<bool> {
+		if self.eof {
+			return Ok(false);
+		}
+
+		// -- Read until the chunk is full or end of file
+		let mut filled = 0;
+		while filled < self.bytes.len() {
+			match self.file.read(&mut self.bytes[filled..]) {
+				Ok(0) => {
+					self.eof = true;
+					break;
+				}
+				Ok(n) => filled += n,
+				Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+				Err(e) => return Err(Error::FileCantRead((self.path.as_path(), e).into())),
+			}
+		}
+
+		// -- Convert the complete values (a partial trailing value is kept as pending error)
+		let size = size_of::<T>();
+		let trailing = filled % size;
+		if trailing != 0 {
+			self.pending_err = Some(Error::BinPartialRecord {
+				path: self.path.to_string_lossy().to_string(),
+				record_size: size,
+				trailing,
+			});
+		}
+		let full = filled - trailing;
+		self.values.clear();
+		self.values.resize(full / size, T::default());
+		(self.read_into)(&self.bytes[..full], &mut self.values);
+		self.pos = 0;
+
+		Ok(!self.values.is_empty())
+	}
+}
+
+impl<T: Copy + Default> Iterator for BinNumIter<T> {
+	type Item = Result<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.pos >= self.values.len() {
+			match self.fill_chunk() {
+				Ok(true) => (),
+				Ok(false) => return self.pending_err.take().map(Err),
+				Err(err) => {
+					self.eof = true;
+					return Some(Err(err));
+				}
+			}
+		}
+
+		let value = self.values[self.pos];
+		self.pos += 1;
+		Some(Ok(value))
+	}
+}
+
+impl<T> std::fmt::Debug for BinNumIter<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("BinNumIter")
+			.field("path", &self.path)
+			.field("pos", &self.pos)
+			.field("eof", &self.eof)
+			.finish()
+	}
+}
+
+// endregion: --- BinNumIter
+
+// region:    --- Generic Stream & Random Access
+
+/// Returns a streaming iterator over the `T` numbers of a flat binary file with the `E` byte order.
+pub fn iter_nums<T: BinNum, E: ByteOrder>(file_path: impl AsRef<Path>) -> Result<BinNumIter<T>> {
+	BinNumIter::open(file_path.as_ref(), T::read_into::<E>)
+}
+
+/// Reads the `T` number at `index` (in values, not bytes) with a positioned read (the file is not loaded).
+///
+/// Returns an `Error::BinIndexOutOfBounds` if the index is past the last value.
+pub fn read_num_at<T: BinNum, E: ByteOrder>(file_path: impl AsRef<Path>, index: u64) -> Result<T> {
+	let file_path = file_path.as_ref();
+	let mut bytes = vec![0u8; size_of::<T>()];
+	read_num_bytes_at(file_path, index, &mut bytes)?;
+
+	let mut value = [T::default()];
+	T::read_into::<E>(&bytes, &mut value);
+	Ok(value[0])
+}
+
+/// Appends the `T` numbers with the `E` byte order to the file (created if missing).
+pub fn append_nums<T: BinNum, E: ByteOrder>(file_path: impl AsRef<Path>, data: &[T]) -> Result<()> {
+	let file_path = file_path.as_ref();
+	let file = OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(file_path)
+		.map_err(|e| Error::FileCantOpen((file_path, e).into()))?;
+
+	let mut writer = BufWriter::new(file);
+	write_nums(&mut writer, data, T::write_into::<E>, file_path)?;
+	writer.flush().map_err(|e| Error::FileCantWrite((file_path, e).into()))?;
+
+	Ok(())
+}
+
+// endregion: --- Generic Stream & Random Access
+
+// region:    --- Stream & Random Access Functions
+
+macro_rules! generate_stream_functions {
+	( $( $type:ty,
+		$iter_be_fn_name:ident, $iter_le_fn_name:ident,
+		$read_be_at_fn_name:ident, $read_le_at_fn_name:ident,
+		$append_be_fn_name:ident, $append_le_fn_name:ident );* $(;)? ) => {
+		$(
+			pub fn $iter_be_fn_name(file_path: impl AsRef<Path>) -> Result<BinNumIter<$type>> {
+				iter_nums::<$type, BigEndian>(file_path)
+			}
+
+			pub fn $iter_le_fn_name(file_path: impl AsRef<Path>) -> Result<BinNumIter<$type>> {
+				iter_nums::<$type, LittleEndian>(file_path)
+			}
+
+			pub fn $read_be_at_fn_name(file_path: impl AsRef<Path>, index: u64) -> Result<$type> {
+				read_num_at::<$type, BigEndian>(file_path, index)
+			}
+
+			pub fn $read_le_at_fn_name(file_path: impl AsRef<Path>, index: u64) -> Result<$type> {
+				read_num_at::<$type, LittleEndian>(file_path, index)
+			}
+
+			pub fn $append_be_fn_name(file_path: impl AsRef<Path>, data: &[$type]) -> Result<()> {
+				append_nums::<$type, BigEndian>(file_path, data)
+			}
+
+			pub fn $append_le_fn_name(file_path: impl AsRef<Path>, data: &[$type]) -> Result<()> {
+				append_nums::<$type, LittleEndian>(file_path, data)
+			}
+		)*
+	};
+}
+
+generate_stream_functions!(
+	f64, iter_be_f64, iter_le_f64, read_be_f64_at, read_le_f64_at, append_be_f64, append_le_f64;
+	f32, iter_be_f32, iter_le_f32, read_be_f32_at, read_le_f32_at, append_be_f32, append_le_f32;
+	f16, iter_be_f16, iter_le_f16, read_be_f16_at, read_le_f16_at, append_be_f16, append_le_f16;
+	u128, iter_be_u128, iter_le_u128, read_be_u128_at, read_le_u128_at, append_be_u128, append_le_u128;
+	u64, iter_be_u64, iter_le_u64, read_be_u64_at, read_le_u64_at, append_be_u64, append_le_u64;
+	u32, iter_be_u32, iter_le_u32, read_be_u32_at, read_le_u32_at, append_be_u32, append_le_u32;
+	u16, iter_be_u16, iter_le_u16, read_be_u16_at, read_le_u16_at, append_be_u16, append_le_u16;
+	i128, iter_be_i128, iter_le_i128, read_be_i128_at, read_le_i128_at, append_be_i128, append_le_i128;
+	i64, iter_be_i64, iter_le_i64, read_be_i64_at, read_le_i64_at, append_be_i64, append_le_i64;
+	i32, iter_be_i32, iter_le_i32, read_be_i32_at, read_le_i32_at, append_be_i32, append_le_i32;
+	i16, iter_be_i16, iter_le_i16, read_be_i16_at, read_le_i16_at, append_be_i16, append_le_i16;
+);
+
+// NOTE: Single byte types have no byte order, so they have a single function of each kind.
+macro_rules! generate_byte_stream_functions {
+	( $( $type:ty, $iter_fn_name:ident, $read_at_fn_name:ident, $append_fn_name:ident );* $(;)? ) => {
+		$(
+			pub fn $iter_fn_name(file_path: impl AsRef<Path>) -> Result<BinNumIter<$type>> {
+				iter_nums::<$type, LittleEndian>(file_path)
+			}
+
+			pub fn $read_at_fn_name(file_path: impl AsRef<Path>, index: u64) -> Result<$type> {
+				read_num_at::<$type, LittleEndian>(file_path, index)
+			}
+
+			pub fn $append_fn_name(file_path: impl AsRef<Path>, data: &[$type]) -> Result<()> {
+				append_nums::<$type, LittleEndian>(file_path, data)
+			}
+		)*
+	};
+}
+
+generate_byte_stream_functions!(
+	u8, iter_u8, read_u8_at, append_u8;
+	i8, iter_i8, read_i8_at, append_i8;
+);
+
+// endregion: --- Stream & Random Access Functions
+
+// region:    --- Support
+
+/// Reads the bytes of the value at `index` (in values, not bytes) with a positioned read.
+fn read_num_bytes_at(file_path: &Path, index: u64, buf: &mut [u8]) -> Result<()> {
+	let file = open_file(file_path)?;
+	let size = buf.len() as u64;
+
+	let res = index
+		.checked_mul(size)
+		.ok_or_else(|| std::io::Error::from(ErrorKind::UnexpectedEof))
+		.and_then(|offset| read_exact_at(&file, offset, buf));
+
+	match res {
+		Ok(()) => Ok(()),
+		Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+			let file_len = file
+				.metadata()
+				.map_err(|e| Error::CantGetMetadata((file_path, e).into()))?
+				.len();
+			Err(Error::BinIndexOutOfBounds {
+				path: file_path.to_string_lossy().to_string(),
+				index,
+				count: file_len / size,
+			})
+		}
+		Err(e) => Err(Error::FileCantRead((file_path, e).into())),
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{ensure_file_dir, load_le_f32, save_le_f32};
+	use std::fs;
+
+	#[test]
+	fn test_featured_bin_nums_iter_append_read_at() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/bin-nums-stream/samples.bin");
+		ensure_file_dir(file)?;
+		// more than one chunk of values
+		let data: Vec<f32> = (0..40_000).map(|i| i as f32 * 0.5).collect();
+		save_le_f32(file, &data[..30_000])?;
+
+		// -- Exec
+		append_le_f32(file, &data[30_000..])?;
+		let iterated = iter_le_f32(file)?.collect::<crate::Result<Vec<f32>>>()?;
+		let loaded = load_le_f32(file)?;
+		let at = read_le_f32_at(file, 39_999)?;
+		let out_of_bounds = read_le_f32_at(file, 40_000);
+
+		// -- Check
+		assert_eq!(iterated, data);
+		assert_eq!(loaded, data);
+		assert_eq!(at, 39_999. * 0.5);
+		assert!(matches!(
+			out_of_bounds,
+			Err(Error::BinIndexOutOfBounds {
+				index: 40_000,
+				count: 40_000,
+				..
+			})
+		));
+
+		// -- Check partial trailing value
+		let mut content = fs::read(file)?;
+		content.push(0);
+		fs::write(file, content)?;
+		let last_two: Vec<_> = iter_le_f32(file)?.skip(39_999).collect();
+		assert_eq!(last_two.len(), 2);
+		assert!(matches!(last_two[1], Err(Error::BinPartialRecord { trailing: 1, .. })));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_bin_nums_single_byte_stream() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/bin-nums-stream-byte/samples.bin");
+		ensure_file_dir(file)?;
+		if file.exists() {
+			fs::remove_file(file)?;
+		}
+
+		// -- Exec
+		append_i8(file, &[-128, -1])?;
+		append_i8(file, &[0, 127])?;
+		let iterated = iter_i8(file)?.collect::<crate::Result<Vec<i8>>>()?;
+		let at = read_u8_at(file, 1)?;
+
+		// -- Check
+		assert_eq!(iterated, [-128, -1, 0, 127]);
+		assert_eq!(at, 0xFF);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,54 @@
+use crate::{Error, Result};
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+
+/// Reads exactly `buf.len()` bytes (one record) from the reader.
+///
+/// Returns `Ok(false)` at a clean end of file (no byte read),
+/// and an `Error::BinPartialRecord` when the file ends in the middle of the record.
+pub(super) fn read_record(reader: &mut impl Read, buf: &mut [u8], file_path: &Path) -> Result<bool> {
+	let mut read = 0;
+
+	while read < buf.len() {
+		match reader.read(&mut buf[read..]) {
+			Ok(0) => break,
+			Ok(n) => read += n,
+			Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+			Err(e) => return Err(Error::FileCantRead((file_path, e).into())),
+		}
+	}
+
+	match read {
+		0 => Ok(false),
+		n if n == buf.len() => Ok(true),
+		n => Err(Error::BinPartialRecord {
+			path: file_path.to_string_lossy().to_string(),
+			record_size: buf.len(),
+			trailing: n,
+		}),
+	}
+}
+
+/// Size (in bytes) of the chunks read/written and converted at once by the flat number functions.
+pub(super) const BIN_NUMS_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Writes the values to the writer, converting them by chunk of `BIN_NUMS_CHUNK_BYTES`.
+pub(super) fn write_nums<T>(
+	writer: &mut impl Write,
+	data: &[T],
+	write_into: fn(&[T], &mut [u8]),
+	file_path: &Path,
+) -> Result<()> {
+	let size = size_of::<T>();
+	let mut buf = vec![0u8; BIN_NUMS_CHUNK_BYTES];
+
+	for chunk in data.chunks(BIN_NUMS_CHUNK_BYTES / size) {
+		let bytes = &mut buf[..size_of_val(chunk)];
+		write_into(chunk, bytes);
+		writer
+			.write_all(bytes)
+			.map_err(|e| Error::FileCantWrite((file_path, e).into()))?;
+	}
+
+	Ok(())
+}
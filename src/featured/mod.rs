@@ -1,11 +1,28 @@
 // region:    --- Modules
 
+#[cfg(any(feature = "with-json", feature = "with-toml", feature = "with-yaml"))]
+mod auto;
 #[cfg(feature = "bin-nums")]
 mod bin_nums;
+#[cfg(feature = "with-archive")]
+mod with_archive;
+#[cfg(feature = "with-compression")]
+mod with_compression;
 #[cfg(feature = "with-json")]
 mod with_json;
 #[cfg(feature = "with-toml")]
 mod with_toml;
+#[cfg(feature = "with-yaml")]
+mod with_yaml;
+
+#[cfg(any(feature = "with-json", feature = "with-toml", feature = "with-yaml"))]
+pub use auto::*;
+
+#[cfg(feature = "with-archive")]
+pub use with_archive::*;
+
+#[cfg(feature = "with-compression")]
+pub use with_compression::*;
 
 #[cfg(feature = "with-json")]
 pub use with_json::*;
@@ -13,6 +30,9 @@ pub use with_json::*;
 #[cfg(feature = "with-toml")]
 pub use with_toml::*;
 
+#[cfg(feature = "with-yaml")]
+pub use with_yaml::*;
+
 #[cfg(feature = "bin-nums")]
 pub use bin_nums::*;
 
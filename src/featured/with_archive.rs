@@ -0,0 +1,388 @@
+use crate::error::{Cause, PathAndCause};
+use crate::{Error, Result, SPath, create_file, ensure_dir, ensure_file_dir, open_file};
+use camino::Utf8Component;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use tar::EntryType;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Creates a tar archive at `dest` with the `files`, each stored with its path relative to `base`
+/// (e.g., from a `list_files(base, ...)`).
+///
+/// When `dest` ends with `.gz` or `.tgz`, the archive is gzip compressed.
+///
+/// Returns the number of files added.
+pub fn create_tar<I>(dest: impl AsRef<SPath>, base: impl AsRef<SPath>, files: I) -> Result<usize>
+where
+	I: IntoIterator<Item: AsRef<SPath>>,
+{
+	let dest = dest.as_ref();
+	let base = base.as_ref();
+
+	let writer = BufWriter::new(create_file(dest)?);
+	let ext = dest.ext().to_ascii_lowercase();
+
+	let count = if ext == "gz" || ext == "tgz" {
+		let encoder = GzEncoder::new(writer, flate2::Compression::default());
+		let (encoder, count) = append_tar_entries(encoder, dest, base, files)?;
+		let mut writer = encoder.finish().map_err(|e| archive_create_err(dest, e))?;
+		writer.flush().map_err(|e| archive_create_err(dest, e))?;
+		count
+	} else {
+		let (mut writer, count) = append_tar_entries(writer, dest, base, files)?;
+		writer.flush().map_err(|e| archive_create_err(dest, e))?;
+		count
+	};
+
+	Ok(count)
+}
+
+/// Creates a zip archive at `dest` with the `files` (deflated), each stored with its path relative to `base`.
+///
+/// Returns the number of files added.
+pub fn create_zip<I>(dest: impl AsRef<SPath>, base: impl AsRef<SPath>, files: I) -> Result<usize>
+where
+	I: IntoIterator<Item: AsRef<SPath>>,
+{
+	let dest = dest.as_ref();
+	let base = base.as_ref();
+
+	let mut zip = ZipWriter::new(BufWriter::new(create_file(dest)?));
+	let mut count = 0;
+
+	for file in files {
+		let file = file.as_ref();
+		let entry_name = entry_name_for(dest, base, file)?;
+
+		let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+		#[cfg(unix)]
+		let options = {
+			use std::os::unix::fs::PermissionsExt;
+			let mode = file.metadata()?.permissions().mode();
+			options.unix_permissions(mode)
+		};
+
+		zip.start_file(entry_name, options)
+			.map_err(|e| Error::ArchiveCantCreate((dest.std_path(), e).into()))?;
+		let mut reader = open_file(file)?;
+		io::copy(&mut reader, &mut zip).map_err(|e| archive_create_err(dest, e))?;
+
+		count += 1;
+	}
+
+	let mut writer = zip
+		.finish()
+		.map_err(|e| Error::ArchiveCantCreate((dest.std_path(), e).into()))?;
+	writer.flush().map_err(|e| archive_create_err(dest, e))?;
+
+	Ok(count)
+}
+
+/// Extracts the archive `src` (zip, tar, or gzip compressed tar, detected by the magic bytes) into `dest`.
+///
+/// All entries are validated before anything is written. An entry escaping `dest`
+/// (e.g., `../evil`, `/etc/passwd`, "zip-slip") or being a symlink/hard link
+/// fails with `Error::ArchiveEntryNotSafe`.
+///
+/// Returns the extracted file paths (directories not included).
+pub fn extract_archive(src: impl AsRef<SPath>, dest: impl AsRef<SPath>) -> Result<Vec<SPath>> {
+	let src = src.as_ref();
+	let dest = dest.as_ref();
+
+	match ArchiveKind::from_file(src)? {
+		ArchiveKind::Zip => extract_zip(src, dest),
+		ArchiveKind::Tar => extract_tar(src, dest, false),
+		ArchiveKind::TarGz => extract_tar(src, dest, true),
+	}
+}
+
+// region:    --- ArchiveKind
+
+enum ArchiveKind {
+	Zip,
+	Tar,
+	TarGz,
+}
+
+impl ArchiveKind {
+	fn from_file(src: &SPath) -> Result<Self> {
+		let mut head = [0u8; 4];
+		let mut file = open_file(src)?;
+		let len = file.read(&mut head).map_err(|e| Error::FileCantRead((src, e).into()))?;
+		let head = &head[..len];
+
+		let kind = if head.starts_with(b"PK") {
+			Self::Zip
+		} else if head.starts_with(&[0x1f, 0x8b]) {
+			Self::TarGz
+		} else {
+			Self::Tar
+		};
+
+		Ok(kind)
+	}
+}
+
+// endregion: --- ArchiveKind
+
+// region:    --- Tar
+
+fn append_tar_entries<W, I>(writer: W, dest: &SPath, base: &SPath, files: I) -> Result<(W, usize)>
+where
+	W: Write,
+	I: IntoIterator<Item: AsRef<SPath>>,
+{
+	let mut builder = tar::Builder::new(writer);
+	let mut count = 0;
+
+	for file in files {
+		let file = file.as_ref();
+		let entry_name = entry_name_for(dest, base, file)?;
+		builder
+			.append_path_with_name(file, entry_name)
+			.map_err(|e| archive_create_err(dest, e))?;
+		count += 1;
+	}
+
+	let writer = builder.into_inner().map_err(|e| archive_create_err(dest, e))?;
+
+	Ok((writer, count))
+}
+
+fn open_tar(src: &SPath, gz: bool) -> Result<tar::Archive<Box<dyn Read>>> {
+	let reader = BufReader::new(open_file(src)?);
+	let reader: Box<dyn Read> = if gz {
+		Box::new(MultiGzDecoder::new(reader))
+	} else {
+		Box::new(reader)
+	};
+	Ok(tar::Archive::new(reader))
+}
+
+fn extract_tar(src: &SPath, dest: &SPath, gz: bool) -> Result<Vec<SPath>> {
+	// -- First pass, validate all the entries
+	let mut archive = open_tar(src, gz)?;
+	for entry in archive.entries().map_err(|e| archive_extract_err(src, e))? {
+		let entry = entry.map_err(|e| archive_extract_err(src, e))?;
+		let name = tar_entry_name(src, &entry)?;
+		if matches!(entry.header().entry_type(), EntryType::Symlink | EntryType::Link) {
+			return Err(not_safe_err(src, &name));
+		}
+		safe_entry_path(src, dest, &name)?;
+	}
+
+	// -- Second pass, extract
+	let mut extracted = Vec::new();
+	let mut archive = open_tar(src, gz)?;
+	for entry in archive.entries().map_err(|e| archive_extract_err(src, e))? {
+		let mut entry = entry.map_err(|e| archive_extract_err(src, e))?;
+		let name = tar_entry_name(src, &entry)?;
+		let target = safe_entry_path(src, dest, &name)?;
+
+		match entry.header().entry_type() {
+			EntryType::Directory => {
+				ensure_dir(&target)?;
+			}
+			EntryType::Regular | EntryType::Continuous => {
+				ensure_file_dir(&target)?;
+				entry.unpack(&target).map_err(|e| archive_extract_err(src, e))?;
+				extracted.push(target);
+			}
+			// NOTE: Other entry types (fifo, devices, ...) are skipped
+			_ => (),
+		}
+	}
+
+	Ok(extracted)
+}
+
+fn tar_entry_name<R: Read>(src: &SPath, entry: &tar::Entry<'_, R>) -> Result<String> {
+	let path = entry.path().map_err(|e| archive_extract_err(src, e))?;
+	Ok(path.to_string_lossy().to_string())
+}
+
+// endregion: --- Tar
+
+// region:    --- Zip
+
+fn extract_zip(src: &SPath, dest: &SPath) -> Result<Vec<SPath>> {
+	let zip_err = |e: zip::result::ZipError| Error::ArchiveCantExtract((src.std_path(), e).into());
+
+	let mut archive = ZipArchive::new(BufReader::new(open_file(src)?)).map_err(zip_err)?;
+
+	// -- First pass, validate all the entries
+	for idx in 0..archive.len() {
+		let file = archive.by_index(idx).map_err(zip_err)?;
+		if file.is_symlink() {
+			return Err(not_safe_err(src, file.name()));
+		}
+		safe_entry_path(src, dest, file.name())?;
+	}
+
+	// -- Second pass, extract
+	let mut extracted = Vec::new();
+	for idx in 0..archive.len() {
+		let mut file = archive.by_index(idx).map_err(zip_err)?;
+		let target = safe_entry_path(src, dest, file.name())?;
+
+		if file.is_dir() {
+			ensure_dir(&target)?;
+			continue;
+		}
+
+		ensure_file_dir(&target)?;
+		let mut writer = BufWriter::new(create_file(&target)?);
+		io::copy(&mut file, &mut writer).map_err(|e| archive_extract_err(src, e))?;
+		writer.flush().map_err(|e| Error::FileCantWrite((&target, e).into()))?;
+
+		#[cfg(unix)]
+		if let Some(mode) = file.unix_mode() {
+			use std::os::unix::fs::PermissionsExt;
+			std::fs::set_permissions(&target, std::fs::Permissions::from_mode(mode & 0o777))
+				.map_err(|e| Error::FileCantWrite((&target, e).into()))?;
+		}
+
+		extracted.push(target);
+	}
+
+	Ok(extracted)
+}
+
+// endregion: --- Zip
+
+// region:    --- Support
+
+/// Returns the archive entry name of `file` relative to `base` (with `/` separators).
+fn entry_name_for(dest: &SPath, base: &SPath, file: &SPath) -> Result<String> {
+	let rel = file.try_diff(base)?.into_collapsed();
+
+	if rel.as_str().is_empty() || !is_relative_inside(&rel) {
+		return Err(Error::ArchiveCantCreate(PathAndCause {
+			path: dest.to_string(),
+			cause: Cause::Custom(format!("File '{file}' is not under the base '{base}'")),
+		}));
+	}
+
+	Ok(rel.as_str().replace('\\', "/"))
+}
+
+/// Returns the destination path of the entry, or an `Error::ArchiveEntryNotSafe`
+/// if the collapsed entry name is absolute or goes above `dest`.
+fn safe_entry_path(src: &SPath, dest: &SPath, entry_name: &str) -> Result<SPath> {
+	let rel = SPath::new(entry_name.replace('\\', "/")).into_collapsed();
+
+	if rel.as_str().is_empty() || !is_relative_inside(&rel) {
+		return Err(not_safe_err(src, entry_name));
+	}
+
+	Ok(dest.join(rel.as_str()))
+}
+
+/// True if the (collapsed) path is relative and does not start with `..`
+fn is_relative_inside(path: &SPath) -> bool {
+	path.path()
+		.components()
+		.all(|c| matches!(c, Utf8Component::Normal(_) | Utf8Component::CurDir))
+}
+
+fn not_safe_err(src: &SPath, entry_name: &str) -> Error {
+	Error::ArchiveEntryNotSafe {
+		archive: src.to_string(),
+		entry: entry_name.to_string(),
+	}
+}
+
+fn archive_create_err(dest: &SPath, err: io::Error) -> Error {
+	Error::ArchiveCantCreate((dest, err).into())
+}
+
+fn archive_extract_err(src: &SPath, err: io::Error) -> Error {
+	Error::ArchiveCantExtract((src, err).into())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::list_files;
+	use std::fs;
+
+	fn setup_src(dir: &SPath) -> Result<SPath> {
+		if dir.exists() {
+			fs::remove_dir_all(dir)?;
+		}
+		let src = dir.join("src");
+		ensure_dir(src.join("sub"))?;
+		fs::write(src.join("a.txt"), "AAA")?;
+		fs::write(src.join("sub/b.md"), "BBB")?;
+		fs::write(src.join("sub/c.txt"), "CCC")?;
+		Ok(src)
+	}
+
+	#[test]
+	fn test_featured_archive_tar_gz_and_zip_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/archive-roundtrip");
+		let src = setup_src(&dir)?;
+		let files = list_files(&src, Some(&["**/*.txt"]), None)?;
+
+		for archive_name in ["out.tar.gz", "out.zip"] {
+			let archive = dir.join(archive_name);
+			let out_dir = dir.join(format!("out-{archive_name}"));
+
+			// -- Exec
+			let count = if archive_name.ends_with(".zip") {
+				create_zip(&archive, &src, &files)?
+			} else {
+				create_tar(&archive, &src, &files)?
+			};
+			let extracted = extract_archive(&archive, &out_dir)?;
+
+			// -- Check
+			assert_eq!(count, 2);
+			assert_eq!(extracted.len(), 2);
+			assert_eq!(fs::read_to_string(out_dir.join("a.txt"))?, "AAA");
+			assert_eq!(fs::read_to_string(out_dir.join("sub/c.txt"))?, "CCC");
+			assert!(!out_dir.join("sub/b.md").exists());
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_archive_extract_zip_slip() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/archive-zip-slip");
+		setup_src(&dir)?;
+		let archive = dir.join("evil.zip");
+		let mut zip = ZipWriter::new(fs::File::create(&archive)?);
+		zip.start_file("ok.txt", SimpleFileOptions::default())?;
+		zip.write_all(b"ok")?;
+		zip.start_file("sub/../../evil.txt", SimpleFileOptions::default())?;
+		zip.write_all(b"evil")?;
+		zip.finish()?;
+		let out_dir = dir.join("out");
+
+		// -- Exec
+		let res = extract_archive(&archive, &out_dir);
+
+		// -- Check
+		let Err(Error::ArchiveEntryNotSafe { entry, .. }) = res else {
+			return Err("should be ArchiveEntryNotSafe".into());
+		};
+		assert_eq!(entry, "sub/../../evil.txt");
+		assert!(!dir.join("evil.txt").exists());
+		assert!(!out_dir.join("ok.txt").exists());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,241 @@
+use crate::{Error, Result, create_file, open_file};
+use flate2::bufread::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// The compression formats supported by the `with-compression` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+	Gzip,
+	Zstd,
+}
+
+impl Compression {
+	/// Returns the compression for the file extension (case insensitive),
+	/// `gz` / `gzip` for Gzip and `zst` / `zstd` for Zstd.
+	pub fn from_ext(file_path: impl AsRef<Path>) -> Option<Self> {
+		let ext = file_path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+		match ext.as_str() {
+			"gz" | "gzip" => Some(Self::Gzip),
+			"zst" | "zstd" => Some(Self::Zstd),
+			_ => None,
+		}
+	}
+
+	/// Returns the compression matching the magic bytes at the start of `bytes`.
+	pub fn from_magic(bytes: &[u8]) -> Option<Self> {
+		if bytes.starts_with(GZIP_MAGIC) {
+			Some(Self::Gzip)
+		} else if bytes.starts_with(ZSTD_MAGIC) {
+			Some(Self::Zstd)
+		} else {
+			None
+		}
+	}
+}
+
+/// Returns a buffered reader of the decompressed content of the file.
+///
+/// The compression is detected by the magic bytes (not the extension),
+/// and plain files are read as is.
+pub fn get_decompressed_buf_reader(file_path: impl AsRef<Path>) -> Result<Box<dyn BufRead + Send>> {
+	let file_path = file_path.as_ref();
+	let mut buf_reader = BufReader::new(open_file(file_path)?);
+
+	let head = buf_reader.fill_buf().map_err(|e| Error::FileCantRead((file_path, e).into()))?;
+
+	let reader: Box<dyn BufRead + Send> = match Compression::from_magic(head) {
+		Some(Compression::Gzip) => Box::new(BufReader::new(MultiGzDecoder::new(buf_reader))),
+		Some(Compression::Zstd) => {
+			let decoder =
+				zstd::Decoder::with_buffer(buf_reader).map_err(|e| Error::FileCantRead((file_path, e).into()))?;
+			Box::new(BufReader::new(decoder))
+		}
+		None => Box::new(buf_reader),
+	};
+
+	Ok(reader)
+}
+
+/// Returns a writer compressing the content by the file extension (see [`Compression::from_ext`]),
+/// or writing as is for other extensions.
+///
+/// Call [`CompressedBufWriter::finish`] to get the eventual error of the last write
+/// (on drop, the content is finished but the error is ignored).
+pub fn get_compressed_buf_writer(file_path: impl AsRef<Path>) -> Result<CompressedBufWriter> {
+	let file_path = file_path.as_ref();
+	let buf_writer = BufWriter::new(create_file(file_path)?);
+
+	let inner = match Compression::from_ext(file_path) {
+		Some(Compression::Gzip) => WriterInner::Gzip(GzEncoder::new(buf_writer, flate2::Compression::default())),
+		Some(Compression::Zstd) => {
+			let encoder = zstd::Encoder::new(buf_writer, zstd::DEFAULT_COMPRESSION_LEVEL)
+				.map_err(|e| Error::FileCantCreate((file_path, e).into()))?;
+			WriterInner::Zstd(encoder)
+		}
+		None => WriterInner::Plain(buf_writer),
+	};
+
+	Ok(CompressedBufWriter {
+		path: file_path.to_path_buf(),
+		inner: Some(inner),
+	})
+}
+
+// region:    --- CompressedBufWriter
+
+/// A buffered file writer, compressing (Gzip, Zstd) or not, returned by [`get_compressed_buf_writer`].
+pub struct CompressedBufWriter {
+	path: PathBuf,
+	// NOTE: Option so that the inner writer can be finished (consumed) from `finish` or `drop`
+	inner: Option<WriterInner>,
+}
+
+enum WriterInner {
+	Plain(BufWriter<File>),
+	Gzip(GzEncoder<BufWriter<File>>),
+	Zstd(zstd::Encoder<'static, BufWriter<File>>),
+}
+
+impl CompressedBufWriter {
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Writes the compression end (if any) and flushes the file.
+	pub fn finish(mut self) -> Result<()> {
+		self.finish_inner()
+			.map_err(|e| Error::FileCantWrite((self.path.as_path(), e).into()))
+	}
+
+	fn finish_inner(&mut self) -> io::Result<()> {
+		let mut buf_writer = match self.inner.take() {
+			Some(WriterInner::Plain(w)) => w,
+			Some(WriterInner::Gzip(w)) => w.finish()?,
+			Some(WriterInner::Zstd(w)) => w.finish()?,
+			None => return Ok(()),
+		};
+		buf_writer.flush()
+	}
+
+	fn inner_writer(&mut self) -> io::Result<&mut dyn Write> {
+		match self.inner.as_mut() {
+			Some(WriterInner::Plain(w)) => Ok(w),
+			Some(WriterInner::Gzip(w)) => Ok(w),
+			Some(WriterInner::Zstd(w)) => Ok(w),
+			None => Err(io::Error::other("writer already finished")),
+		}
+	}
+}
+
+impl Write for CompressedBufWriter {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.inner_writer()?.write(buf)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner_writer()?.flush()
+	}
+}
+
+impl Drop for CompressedBufWriter {
+	fn drop(&mut self) {
+		let _ = self.finish_inner();
+	}
+}
+
+impl std::fmt::Debug for CompressedBufWriter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let compression = match self.inner {
+			Some(WriterInner::Gzip(_)) => Some(Compression::Gzip),
+			Some(WriterInner::Zstd(_)) => Some(Compression::Zstd),
+			_ => None,
+		};
+		f.debug_struct("CompressedBufWriter")
+			.field("path", &self.path)
+			.field("compression", &compression)
+			.finish()
+	}
+}
+
+// endregion: --- CompressedBufWriter
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_dir;
+	use std::io::Read;
+
+	#[test]
+	fn test_featured_compression_roundtrip_by_magic() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/compression-roundtrip");
+		ensure_dir(dir)?;
+		let content = "line one\nline two\n".repeat(100);
+
+		for (name, compression) in [
+			("data.txt.gz", Some(Compression::Gzip)),
+			("data.txt.zst", Some(Compression::Zstd)),
+			("data.txt", None),
+		] {
+			let file = dir.join(name);
+
+			// -- Exec
+			let mut writer = get_compressed_buf_writer(&file)?;
+			writer.write_all(content.as_bytes())?;
+			writer.finish()?;
+			let mut read = String::new();
+			get_decompressed_buf_reader(&file)?.read_to_string(&mut read)?;
+
+			// -- Check
+			let head = std::fs::read(&file)?;
+			assert_eq!(Compression::from_magic(&head), compression, "{name}");
+			assert_eq!(read, content, "{name}");
+		}
+
+		Ok(())
+	}
+
+	#[cfg(feature = "with-json")]
+	#[test]
+	fn test_featured_compression_json_helpers() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/compression-json");
+		ensure_dir(dir)?;
+		let data = serde_json::json!({"name": "archive", "ids": [1, 2, 3]});
+		let ndjson_file = dir.join("events.ndjson.gz");
+		let mut writer = get_compressed_buf_writer(&ndjson_file)?;
+		writer.write_all(b"{\"id\":1}\n{\"id\":2}\n")?;
+		writer.finish()?;
+
+		// -- Exec
+		let json_file = dir.join("data.json.zst");
+		crate::save_json(&json_file, &data)?;
+		let loaded: serde_json::Value = crate::load_json(&json_file)?;
+		let events = crate::load_ndjson(&ndjson_file)?;
+		let streamed = crate::stream_ndjson(&ndjson_file)?.count();
+
+		// -- Check
+		assert_eq!(
+			Compression::from_magic(&std::fs::read(&json_file)?),
+			Some(Compression::Zstd)
+		);
+		assert_eq!(loaded, data);
+		assert_eq!(events.len(), 2);
+		assert_eq!(events[1]["id"], 2);
+		assert_eq!(streamed, 2);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,128 @@
+/// Converts a JSONC content (JSON with `//` and `/* */` comments and trailing commas) to plain JSON.
+///
+/// Comments and trailing commas are replaced by spaces (newlines in block comments are kept),
+/// so the byte offsets, lines, and columns of the result are the same as the input.
+/// This keeps the line/column of eventual `serde_json` errors accurate for the original content.
+pub fn jsonc_to_json(input: &str) -> String {
+	let mut out = input.as_bytes().to_vec();
+	let len = out.len();
+
+	// Index of the last ',' seen that could be a trailing comma
+	let mut pending_comma: Option<usize> = None;
+	let mut in_string = false;
+	let mut i = 0;
+
+	while i < len {
+		let b = out[i];
+
+		// -- In string, only look for the end quote (skip escaped chars)
+		if in_string {
+			match b {
+				b'\\' => i += 1,
+				b'"' => in_string = false,
+				_ => {}
+			}
+			i += 1;
+			continue;
+		}
+
+		match b {
+			b'"' => {
+				in_string = true;
+				pending_comma = None;
+			}
+			// line comment, blank until end of line
+			b'/' if out.get(i + 1) == Some(&b'/') => {
+				while i < len && out[i] != b'\n' {
+					out[i] = b' ';
+					i += 1;
+				}
+				continue;
+			}
+			// block comment, blank until `*/` (keep the new lines)
+			b'/' if out.get(i + 1) == Some(&b'*') => {
+				out[i] = b' ';
+				out[i + 1] = b' ';
+				i += 2;
+				while i < len {
+					if out[i] == b'*' && out.get(i + 1) == Some(&b'/') {
+						out[i] = b' ';
+						out[i + 1] = b' ';
+						i += 2;
+						break;
+					}
+					if out[i] != b'\n' && out[i] != b'\r' {
+						out[i] = b' ';
+					}
+					i += 1;
+				}
+				continue;
+			}
+			b',' => pending_comma = Some(i),
+			b'}' | b']' => {
+				if let Some(comma_idx) = pending_comma.take() {
+					out[comma_idx] = b' ';
+				}
+			}
+			b if b.is_ascii_whitespace() => {}
+			_ => pending_comma = None,
+		}
+
+		i += 1;
+	}
+
+	// NOTE: Only ASCII bytes were replaced by ASCII spaces (comment bytes are all blanked),
+	//       so the result is still valid UTF-8.
+	String::from_utf8(out).unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned())
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_featured_jsonc_to_json_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		let input = r#"{
+	// editor settings
+	"editor.url": "http://example.com", /* inline
+	block */ "tabs": [1, 2,],
+	"str": "not // a comment, ]",
+}"#;
+
+		// -- Exec
+		let json = jsonc_to_json(input);
+
+		// -- Check
+		assert_eq!(json.len(), input.len());
+		assert_eq!(json.lines().count(), input.lines().count());
+		let value: serde_json::Value = serde_json::from_str(&json)?;
+		assert_eq!(value["editor.url"], "http://example.com");
+		assert_eq!(value["tabs"], serde_json::json!([1, 2]));
+		assert_eq!(value["str"], "not // a comment, ]");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_jsonc_to_json_error_position() -> Result<()> {
+		// -- Setup & Fixtures
+		let input = "{\n  /* a comment */ \"a\": 1,\n  \"b\": [1, x]\n}";
+
+		// -- Exec
+		let err = serde_json::from_str::<serde_json::Value>(&jsonc_to_json(input))
+			.err()
+			.ok_or("should fail")?;
+
+		// -- Check
+		assert_eq!((err.line(), err.column()), (3, 12));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,4 +1,7 @@
-use crate::{Error, Result, get_buf_reader};
+use super::NdjsonLenientLoad;
+use crate::error::Cause;
+use crate::{Error, Result, read_to_string};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::path::Path;
 
@@ -8,24 +11,195 @@ where
 {
 	let file = file.as_ref();
 
-	let buf_reader = get_buf_reader(file)?;
+	let buf_reader = get_json_buf_reader(file)?;
 	let val = serde_json::from_reader(buf_reader).map_err(|ex| Error::JsonCantRead((file, ex).into()))?;
 
 	Ok(val)
 }
 
+/// Loads a JSONC file (JSON with `//` and `/* */` comments and trailing commas, e.g., VS Code settings).
+///
+/// Comments and trailing commas are blanked before parsing (see [`super::jsonc_to_json`]),
+/// so the line/column in an eventual `Error::JsonCantRead` match the original file.
+pub fn load_jsonc<T>(file: impl AsRef<Path>) -> Result<T>
+where
+	T: serde::de::DeserializeOwned,
+{
+	let file = file.as_ref();
+
+	let content = read_to_string(file)?;
+	let json = super::jsonc_to_json(&content);
+	let val = serde_json::from_str(&json).map_err(|ex| Error::JsonCantRead((file, ex).into()))?;
+
+	Ok(val)
+}
+
+/// Loads a JSON5 file (comments, trailing commas, unquoted keys, single quoted strings, ...).
+///
+/// Errors are reported as `Error::JsonCantRead` with a `Cause::Json5` (which has the line/column).
+#[cfg(feature = "with-json5")]
+pub fn load_json5<T>(file: impl AsRef<Path>) -> Result<T>
+where
+	T: serde::de::DeserializeOwned,
+{
+	let file = file.as_ref();
+
+	let content = read_to_string(file)?;
+	let val = json5::from_str(&content).map_err(|ex| Error::JsonCantRead((file, ex).into()))?;
+
+	Ok(val)
+}
+
 /// Loads a ndjson (newline delimited json) file returning `Result<Vec<Value>>`.
 /// Empty lines will be skipped.
 pub fn load_ndjson(file: impl AsRef<Path>) -> Result<Vec<Value>> {
-	let file = file.as_ref();
-	let buf_reader = get_buf_reader(file)?;
-	super::parse_ndjson_from_reader(buf_reader)
+	load_ndjson_as(file)
 }
 
 /// Returns an iterator over each line parsed as json `Result<Value>`.
 /// Empty lines will be skipped.
 pub fn stream_ndjson(file: impl AsRef<Path>) -> Result<impl Iterator<Item = Result<Value>>> {
+	stream_ndjson_as(file)
+}
+
+/// Loads a ndjson file, deserializing each line into `T`.
+/// Empty lines will be skipped.
+///
+/// Fails on the first bad line with an `Error::NdJsonCantReadLine` (path and 1-based line number).
+pub fn load_ndjson_as<T>(file: impl AsRef<Path>) -> Result<Vec<T>>
+where
+	T: DeserializeOwned,
+{
+	stream_ndjson_as(file)?.collect()
+}
+
+/// Loads a ndjson file, deserializing each line into `T`, skipping the bad lines.
+/// Empty lines will be skipped.
+///
+/// The errors of the skipped lines are reported in `NdjsonLenientLoad::skipped`.
+///
+/// Note: Only the JSON parse errors are skipped. A read error (e.g., the path is a directory)
+///       fails the load with its `Error::NdJsonCantReadLine` (with a `Cause::Io`).
+pub fn load_ndjson_as_lenient<T>(file: impl AsRef<Path>) -> Result<NdjsonLenientLoad<T>>
+where
+	T: DeserializeOwned,
+{
+	let mut records = Vec::new();
+	let mut skipped = Vec::new();
+
+	for res in stream_ndjson_as(file)? {
+		match res {
+			Ok(record) => records.push(record),
+			Err(
+				err @ Error::NdJsonCantReadLine {
+					cause: Cause::SerdeJson(_),
+					..
+				},
+			) => skipped.push(err),
+			Err(err) => return Err(err),
+		}
+	}
+
+	Ok(NdjsonLenientLoad { records, skipped })
+}
+
+/// Returns an iterator over each line deserialized into `T` as `Result<T>`.
+/// Empty lines will be skipped.
+///
+/// A bad line yields an `Error::NdJsonCantReadLine` (path and 1-based line number)
+/// but does not end the iteration, so the caller can skip it (e.g., `.filter_map(|r| r.ok())`).
+/// A read error (e.g., the path is a directory) yields its error and ends the iteration.
+pub fn stream_ndjson_as<T>(file: impl AsRef<Path>) -> Result<impl Iterator<Item = Result<T>>>
+where
+	T: DeserializeOwned,
+{
 	let file = file.as_ref();
-	let buf_reader = get_buf_reader(file)?;
-	Ok(super::parse_ndjson_iter_from_reader(buf_reader))
+	let buf_reader = get_json_buf_reader(file)?;
+	Ok(super::parse_ndjson_iter_as_from_reader(
+		buf_reader,
+		file.to_string_lossy().to_string(),
+	))
+}
+
+// region:    --- Support
+
+/// Returns the reader for the json/ndjson loaders,
+/// decompressing gzip/zstd files when the `with-compression` feature is enabled.
+#[cfg(feature = "with-compression")]
+fn get_json_buf_reader(file: &Path) -> Result<Box<dyn std::io::BufRead + Send>> {
+	crate::get_decompressed_buf_reader(file)
+}
+
+#[cfg(not(feature = "with-compression"))]
+fn get_json_buf_reader(file: &Path) -> Result<std::io::BufReader<std::fs::File>> {
+	crate::get_buf_reader(file)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_file_dir;
+	use serde::Deserialize;
+	use std::fs;
+
+	#[derive(Debug, Deserialize, PartialEq)]
+	struct Event {
+		id: u32,
+		name: String,
+	}
+
+	#[test]
+	fn test_featured_ndjson_load_ndjson_as_lenient() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/ndjson-lenient/events.ndjson");
+		ensure_file_dir(file)?;
+		fs::write(
+			file,
+			"{\"id\": 1, \"name\": \"one\"}\n\n{\"id\": \"bad\"}\n{\"id\": 3, \"name\": \"three\"}\n",
+		)?;
+
+		// -- Exec
+		let strict_res = load_ndjson_as::<Event>(file);
+		let lenient = load_ndjson_as_lenient::<Event>(file)?;
+
+		// -- Check
+		let Err(Error::NdJsonCantReadLine { line, path, .. }) = strict_res else {
+			return Err("strict load should fail with NdJsonCantReadLine".into());
+		};
+		assert_eq!(line, 3);
+		assert!(path.ends_with("events.ndjson"));
+
+		let ids: Vec<u32> = lenient.records.iter().map(|e| e.id).collect();
+		assert_eq!(ids, [1, 3]);
+		assert_eq!(lenient.skipped.len(), 1);
+		assert!(matches!(lenient.skipped[0], Error::NdJsonCantReadLine { line: 3, .. }));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_ndjson_load_ndjson_as_lenient_read_error() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/ndjson-lenient-dir/events.ndjson");
+		fs::create_dir_all(dir)?;
+
+		// -- Exec
+		let lenient_res = load_ndjson_as_lenient::<Event>(dir);
+
+		// -- Check
+		assert!(
+			lenient_res.is_err(),
+			"a read error must fail (and end) the lenient load"
+		);
+
+		Ok(())
+	}
 }
+
+// endregion: --- Tests
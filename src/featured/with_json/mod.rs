@@ -1,11 +1,15 @@
 // region:    --- Modules
 
+mod jsonc;
 mod load;
 mod ndjson;
+mod ndjson_log;
 mod save;
 
+pub use jsonc::*;
 pub use load::*;
 pub use ndjson::*;
+pub use ndjson_log::*;
 pub use save::*;
 
 // endregion: --- Modules
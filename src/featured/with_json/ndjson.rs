@@ -1,7 +1,19 @@
+use crate::error::Cause;
 use crate::{Error, Result};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::io::{BufRead, Cursor};
 
+/// The result of a lenient NDJSON load (see `load_ndjson_as_lenient`).
+#[derive(Debug)]
+pub struct NdjsonLenientLoad<T> {
+	/// The successfully parsed records, in file order
+	pub records: Vec<T>,
+
+	/// The errors for the skipped lines (`Error::NdJsonCantReadLine`, with the 1-based line number)
+	pub skipped: Vec<Error>,
+}
+
 // From &str using Cursor (reuses above)
 pub fn parse_ndjson_iter(input: &str) -> impl Iterator<Item = Result<Value>> {
 	let reader = Cursor::new(input);
@@ -19,22 +31,118 @@ pub fn parse_ndjson_from_reader<R: BufRead>(reader: R) -> Result<Vec<Value>> {
 }
 
 // Core streaming parser
+//
+// NOTE: Ends after the first read error (`lines()` would repeat it forever, e.g., on a directory).
 pub fn parse_ndjson_iter_from_reader<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Value>> {
-	reader.lines().enumerate().filter_map(|(index, line_result)| {
-		match line_result {
-			Ok(line) if line.trim().is_empty() => None, // skip empty
-			Ok(line) => Some(serde_json::from_str::<Value>(&line).map_err(|e| {
-				Error::NdJson(format!(
-					"aip.file.load_ndjson - Failed to parse JSON on line {}. Cause: {}",
-					index + 1,
-					e
-				))
-			})),
-			Err(e) => Some(Err(Error::NdJson(format!(
-				"aip.file.load_ndjson - Failed to read line {}. Cause: {}",
-				index + 1,
-				e
-			)))),
+	reader
+		.lines()
+		.enumerate()
+		.scan(false, |read_failed, (index, line_result)| {
+			if *read_failed {
+				return None;
+			}
+			let res = match line_result {
+				Ok(line) if line.trim().is_empty() => None, // skip empty
+				Ok(line) => Some(serde_json::from_str::<Value>(&line).map_err(|e| {
+					Error::NdJson(format!(
+						"aip.file.load_ndjson - Failed to parse JSON on line {}. Cause: {}",
+						index + 1,
+						e
+					))
+				})),
+				Err(e) => {
+					*read_failed = true;
+					Some(Err(Error::NdJson(format!(
+						"aip.file.load_ndjson - Failed to read line {}. Cause: {}",
+						index + 1,
+						e
+					))))
+				}
+			};
+			Some(res)
+		})
+		.flatten()
+}
+
+/// Typed streaming parser, with errors as `Error::NdJsonCantReadLine` carrying the `path` and 1-based line number.
+/// Empty lines are skipped.
+///
+/// Note: A bad line yields an `Err` (with a `Cause::SerdeJson`) but does not stop the iteration.
+///       A read error yields an `Err` (with a `Cause::Io`) and ends the iteration.
+pub(crate) fn parse_ndjson_iter_as_from_reader<T, R>(reader: R, path: String) -> impl Iterator<Item = Result<T>>
+where
+	T: DeserializeOwned,
+	R: BufRead,
+{
+	reader
+		.lines()
+		.enumerate()
+		.scan(false, move |read_failed, (index, line_result)| {
+			if *read_failed {
+				return None;
+			}
+			let res = match line_result {
+				Ok(line) if line.trim().is_empty() => None, // skip empty
+				Ok(line) => Some(serde_json::from_str::<T>(&line).map_err(|e| Error::NdJsonCantReadLine {
+					path: path.clone(),
+					line: index + 1,
+					cause: Cause::SerdeJson(Box::new(e)),
+				})),
+				Err(e) => {
+					*read_failed = true;
+					Some(Err(Error::NdJsonCantReadLine {
+						path: path.clone(),
+						line: index + 1,
+						cause: Cause::Io(Box::new(e)),
+					}))
+				}
+			};
+			Some(res)
+		})
+		.flatten()
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use std::io::{BufReader, Read};
+
+	/// A reader which always fails (like reading a directory).
+	struct FailingReader;
+
+	impl Read for FailingReader {
+		fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+			Err(std::io::Error::other("always fails"))
 		}
-	})
+	}
+
+	#[test]
+	fn test_featured_ndjson_iter_ends_after_read_error() -> Result<()> {
+		// -- Exec
+		let typed: Vec<_> =
+			parse_ndjson_iter_as_from_reader::<Value, _>(BufReader::new(FailingReader), "failing".to_string())
+				.take(10)
+				.collect();
+		let untyped: Vec<_> = parse_ndjson_iter_from_reader(BufReader::new(FailingReader)).take(10).collect();
+
+		// -- Check
+		assert_eq!(typed.len(), 1, "should end after the first read error");
+		assert!(matches!(
+			typed[0],
+			Err(Error::NdJsonCantReadLine {
+				line: 1,
+				cause: Cause::Io(_),
+				..
+			})
+		));
+		assert_eq!(untyped.len(), 1, "should end after the first read error");
+
+		Ok(())
+	}
 }
+
+// endregion: --- Tests
@@ -0,0 +1,342 @@
+use crate::error::Cause;
+use crate::{Error, Result, SPath, open_file, read_to_string, write_atomic};
+use memchr::memchr_iter;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+
+/// An append-only NDJSON writer that rotates its file by size and/or line count.
+///
+/// On rotation, `events.ndjson` becomes `events.1.ndjson`, `events.1.ndjson` becomes `events.2.ndjson`, ...
+/// and when `keep_rotated` is set, the rotated files beyond that count are removed.
+///
+/// Note: A single record is never split, so a file can exceed `max_bytes` when a record is bigger than it.
+#[derive(Debug)]
+pub struct NdjsonLog {
+	path: SPath,
+	max_bytes: Option<u64>,
+	max_lines: Option<usize>,
+	keep_rotated: Option<usize>,
+
+	// -- Current file state
+	file: Option<File>,
+	cur_bytes: u64,
+	cur_lines: usize,
+}
+
+/// Constructor & Fluent API
+impl NdjsonLog {
+	/// Creates a log on `path`. If the file already exists, appends will continue it
+	/// (its current size and line count are taken into account for the rotation).
+	pub fn new(path: impl Into<SPath>) -> Result<Self> {
+		let path = path.into();
+
+		let (cur_bytes, cur_lines) = if path.is_file() {
+			(path.metadata()?.len(), count_lines(&path)?)
+		} else {
+			(0, 0)
+		};
+
+		Ok(Self {
+			path,
+			max_bytes: None,
+			max_lines: None,
+			keep_rotated: None,
+			file: None,
+			cur_bytes,
+			cur_lines,
+		})
+	}
+
+	/// Rotate when writing the next record would make the file bigger than `max_bytes`.
+	pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+		self.max_bytes = Some(max_bytes);
+		self
+	}
+
+	/// Rotate when the file already has `max_lines` records.
+	pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+		self.max_lines = Some(max_lines);
+		self
+	}
+
+	/// Keep only the last `count` rotated files (`events.1.ndjson` being the most recent).
+	pub fn with_keep_rotated(mut self, count: usize) -> Self {
+		self.keep_rotated = Some(count);
+		self
+	}
+}
+
+/// Public API
+impl NdjsonLog {
+	pub fn path(&self) -> &SPath {
+		&self.path
+	}
+
+	/// Appends a value as a JSON line, rotating first if needed.
+	pub fn append<T: Serialize>(&mut self, value: &T) -> Result<()> {
+		let mut line =
+			serde_json::to_string(value).map_err(|e| Error::JsonCantWrite((self.path.std_path(), e).into()))?;
+		line.push('\n');
+
+		if self.should_rotate(line.len() as u64) {
+			self.rotate()?;
+		}
+
+		let file = self.get_file()?;
+		file.write_all(line.as_bytes())
+			.map_err(|e| Error::FileCantWrite((&self.path, e).into()))?;
+
+		self.cur_bytes += line.len() as u64;
+		self.cur_lines += 1;
+
+		Ok(())
+	}
+
+	/// Appends multiple values as JSON lines, rotating as needed between them.
+	pub fn append_all<'a, T, I>(&mut self, values: I) -> Result<()>
+	where
+		T: Serialize + 'a,
+		I: IntoIterator<Item = &'a T>,
+	{
+		for value in values {
+			self.append(value)?;
+		}
+		Ok(())
+	}
+
+	/// Rotates the files now (does nothing if the current file is empty or does not exist).
+	pub fn rotate(&mut self) -> Result<()> {
+		self.file = None;
+
+		if !self.path.is_file() || self.cur_bytes == 0 {
+			return Ok(());
+		}
+
+		// -- Find the highest existing rotated index
+		let mut last_idx = 0;
+		while self.rotated_path(last_idx + 1).exists() {
+			last_idx += 1;
+		}
+
+		// -- Shift the rotated files (from the oldest), dropping the ones beyond keep_rotated
+		for idx in (1..=last_idx).rev() {
+			let from = self.rotated_path(idx);
+			if self.keep_rotated.is_some_and(|keep| idx >= keep) {
+				fs::remove_file(&from).map_err(|e| Error::FileCantWrite((&from, e).into()))?;
+			} else {
+				let to = self.rotated_path(idx + 1);
+				fs::rename(&from, &to).map_err(|e| Error::FileCantWrite((&to, e).into()))?;
+			}
+		}
+
+		// -- Move the current file (or drop it if no rotated file is kept)
+		if self.keep_rotated == Some(0) {
+			fs::remove_file(&self.path).map_err(|e| Error::FileCantWrite((&self.path, e).into()))?;
+		} else {
+			let to = self.rotated_path(1);
+			fs::rename(&self.path, &to).map_err(|e| Error::FileCantWrite((&to, e).into()))?;
+		}
+
+		self.cur_bytes = 0;
+		self.cur_lines = 0;
+
+		Ok(())
+	}
+
+	/// Returns the existing rotated files, most recent first.
+	pub fn rotated_paths(&self) -> Vec<SPath> {
+		(1..)
+			.map(|idx| self.rotated_path(idx))
+			.take_while(|path| path.exists())
+			.collect()
+	}
+}
+
+/// Private
+impl NdjsonLog {
+	fn should_rotate(&self, line_len: u64) -> bool {
+		if self.cur_lines == 0 {
+			return false;
+		}
+		let over_bytes = self.max_bytes.is_some_and(|max| self.cur_bytes + line_len > max);
+		let over_lines = self.max_lines.is_some_and(|max| self.cur_lines >= max);
+		over_bytes || over_lines
+	}
+
+	/// e.g., `events.ndjson` with idx 2 → `events.2.ndjson`
+	fn rotated_path(&self, idx: usize) -> SPath {
+		let name = match self.path.extension() {
+			Some(ext) => format!("{}.{idx}.{ext}", self.path.stem()),
+			None => format!("{}.{idx}", self.path.name()),
+		};
+		self.path.new_sibling(name)
+	}
+
+	fn get_file(&mut self) -> Result<&mut File> {
+		if self.file.is_none() {
+			let file = OpenOptions::new()
+				.create(true)
+				.append(true)
+				.open(&self.path)
+				.map_err(|e| Error::FileCantOpen((&self.path, e).into()))?;
+			self.file = Some(file);
+		}
+
+		self.file
+			.as_mut()
+			.ok_or_else(|| Error::FileCantOpen((&self.path, std::io::Error::other("file not opened")).into()))
+	}
+}
+
+/// Rewrites a ndjson file, keeping only the last record for each value of `key_field`.
+///
+/// - The kept records are in the order of their last occurrence.
+/// - The kept records are written as their original lines (not re-serialized, so the key order is preserved).
+/// - Records without `key_field` (or not objects) are all kept.
+/// - The file is rewritten with `write_atomic`.
+///
+/// Returns the number of records removed.
+pub fn compact_ndjson(file: impl AsRef<SPath>, key_field: &str) -> Result<usize> {
+	let file = file.as_ref();
+
+	// -- Read all records with their original line (empty lines skipped)
+	let content = read_to_string(file)?;
+	let mut records: Vec<(&str, Option<String>)> = Vec::new();
+	for (idx, line) in content.lines().enumerate() {
+		if line.trim().is_empty() {
+			continue;
+		}
+		let record: Value = serde_json::from_str(line).map_err(|e| Error::NdJsonCantReadLine {
+			path: file.to_string(),
+			line: idx + 1,
+			cause: Cause::SerdeJson(Box::new(e)),
+		})?;
+		records.push((line, record.get(key_field).map(|key| key.to_string())));
+	}
+
+	// -- Record index of the last occurrence per key
+	let mut last_idx_by_key: HashMap<&str, usize> = HashMap::new();
+	for (idx, (_, key)) in records.iter().enumerate() {
+		if let Some(key) = key {
+			last_idx_by_key.insert(key, idx);
+		}
+	}
+
+	// -- Keep only the last occurrences
+	let mut compacted = String::with_capacity(content.len());
+	let mut removed = 0;
+	for (idx, (line, key)) in records.iter().enumerate() {
+		let keep = match key {
+			Some(key) => last_idx_by_key.get(key.as_str()) == Some(&idx),
+			None => true,
+		};
+		if keep {
+			compacted.push_str(line);
+			compacted.push('\n');
+		} else {
+			removed += 1;
+		}
+	}
+
+	if removed > 0 {
+		write_atomic(file, compacted)?;
+	}
+
+	Ok(removed)
+}
+
+// region:    --- Support
+
+fn count_lines(path: &SPath) -> Result<usize> {
+	let mut reader = BufReader::new(open_file(path)?);
+	let mut count = 0;
+	loop {
+		let buf = reader.fill_buf().map_err(|e| Error::FileCantRead((path, e).into()))?;
+		if buf.is_empty() {
+			break;
+		}
+		count += memchr_iter(b'\n', buf).count();
+		let len = buf.len();
+		reader.consume(len);
+	}
+	Ok(count)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{ensure_dir, load_ndjson};
+	use serde_json::json;
+
+	fn clean_dir(dir: &SPath) -> Result<()> {
+		if dir.exists() {
+			fs::remove_dir_all(dir)?;
+		}
+		ensure_dir(dir)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_ndjson_log_rotate_by_lines_keep() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/ndjson-log-rotate");
+		clean_dir(&dir)?;
+		let mut log = NdjsonLog::new(dir.join("events.ndjson"))?
+			.with_max_lines(2)
+			.with_keep_rotated(2);
+
+		// -- Exec
+		for id in 1..=7 {
+			log.append(&json!({"id": id}))?;
+		}
+
+		// -- Check
+		let ids = |path: &SPath| -> Result<Vec<i64>> {
+			Ok(load_ndjson(path)?.iter().filter_map(|v| v["id"].as_i64()).collect())
+		};
+		let rotated = log.rotated_paths();
+		assert_eq!(rotated.len(), 2);
+		assert_eq!(rotated[0].name(), "events.1.ndjson");
+		assert_eq!(ids(log.path())?, [7]);
+		assert_eq!(ids(&rotated[0])?, [5, 6]);
+		assert_eq!(ids(&rotated[1])?, [3, 4]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_ndjson_compact_ndjson_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/ndjson-compact");
+		clean_dir(&dir)?;
+		let file = dir.join("state.ndjson");
+		fs::write(
+			&file,
+			"{\"id\":\"a\",\"v\":1}\n{\"v\":1, \"id\":\"b\"}\n{\"other\":true}\n{\"id\":\"a\",\"v\":2}\n",
+		)?;
+
+		// -- Exec
+		let removed = compact_ndjson(&file, "id")?;
+
+		// -- Check
+		assert_eq!(removed, 1);
+		assert_eq!(
+			fs::read_to_string(&file)?,
+			"{\"v\":1, \"id\":\"b\"}\n{\"other\":true}\n{\"id\":\"a\",\"v\":2}\n",
+			"kept records must be the original lines"
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
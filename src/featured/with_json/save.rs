@@ -1,5 +1,4 @@
-use crate::file::create_file;
-use crate::{Error, Result};
+use crate::{Error, Result, lock_file_sibling};
 use serde::Serialize;
 use std::fs::OpenOptions;
 use std::io::{BufWriter, Write};
@@ -25,23 +24,44 @@ fn save_json_impl<T>(file_path: &Path, data: &T, pretty: bool) -> Result<()>
 where
 	T: serde::Serialize,
 {
-	let file = create_file(file_path)?;
+	// NOTE: With the `with-compression` feature, `.gz` / `.zst` files are compressed.
+	#[cfg(feature = "with-compression")]
+	let mut file = crate::get_compressed_buf_writer(file_path)?;
+	#[cfg(not(feature = "with-compression"))]
+	let mut file = crate::create_file(file_path)?;
 
 	let res = if pretty {
-		serde_json::to_writer_pretty(file, data)
+		serde_json::to_writer_pretty(&mut file, data)
 	} else {
-		serde_json::to_writer(file, data)
+		serde_json::to_writer(&mut file, data)
 	};
 
 	res.map_err(|e| Error::JsonCantWrite((file_path, e).into()))?;
 
+	#[cfg(feature = "with-compression")]
+	file.finish()?;
+
 	Ok(())
 }
 
 /// Appends a `serde_json::Value` as a JSON line to the specified file.
 /// Creates the file if it doesn't exist.
 pub fn append_json_line<T: Serialize>(file: impl AsRef<Path>, value: &T) -> Result<()> {
-	let file_path = file.as_ref();
+	append_json_line_impl(file.as_ref(), value, false)
+}
+
+/// Same as [`append_json_line`], but holding an exclusive lock (see [`lock_file_sibling`])
+/// so that concurrent appenders (including from other processes) do not interleave.
+pub fn append_json_line_locked<T: Serialize>(file: impl AsRef<Path>, value: &T) -> Result<()> {
+	append_json_line_impl(file.as_ref(), value, true)
+}
+
+fn append_json_line_impl<T: Serialize>(file_path: &Path, value: &T, locked: bool) -> Result<()> {
+	let _lock = if locked {
+		Some(lock_file_sibling(file_path, true)?)
+	} else {
+		None
+	};
 
 	// Serialize the value to a JSON string first.
 	let json_string = serde_json::to_string(value).map_err(|e| Error::JsonCantWrite((file_path, e).into()))?;
@@ -66,7 +86,29 @@ where
 	T: Serialize + 'a,
 	I: IntoIterator<Item = &'a T>,
 {
-	let file_path = file.as_ref();
+	append_json_lines_impl(file.as_ref(), values, false)
+}
+
+/// Same as [`append_json_lines`], but holding an exclusive lock (see [`lock_file_sibling`])
+/// for the whole batch, so the lines are not interleaved with other locked appenders.
+pub fn append_json_lines_locked<'a, T, I>(file: impl AsRef<Path>, values: I) -> Result<()>
+where
+	T: Serialize + 'a,
+	I: IntoIterator<Item = &'a T>,
+{
+	append_json_lines_impl(file.as_ref(), values, true)
+}
+
+fn append_json_lines_impl<'a, T, I>(file_path: &Path, values: I, locked: bool) -> Result<()>
+where
+	T: Serialize + 'a,
+	I: IntoIterator<Item = &'a T>,
+{
+	let _lock = if locked {
+		Some(lock_file_sibling(file_path, true)?)
+	} else {
+		None
+	};
 
 	// Open the file in append mode, creating it if necessary.
 	let file = OpenOptions::new()
@@ -98,3 +140,49 @@ where
 
 	Ok(())
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_dir;
+	use serde_json::json;
+	use std::fs;
+	use std::thread;
+	use std::time::Duration;
+
+	#[test]
+	fn test_featured_json_append_locked_uses_sibling_lock() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/json-append-locked");
+		ensure_dir(dir)?;
+		let file = dir.join("events.ndjson");
+		fs::write(&file, "")?;
+
+		// -- Exec
+		let lock = lock_file_sibling(&file, true)?;
+		let appender = {
+			let file = file.clone();
+			thread::spawn(move || -> crate::Result<()> {
+				append_json_line_locked(&file, &json!({"id": 1}))?;
+				append_json_lines_locked(&file, &[json!({"id": 2}), json!({"id": 3})])
+			})
+		};
+		thread::sleep(Duration::from_millis(100));
+		let content_while_locked = fs::read_to_string(&file)?;
+		drop(lock);
+		appender.join().map_err(|_| "appender thread panicked")??;
+
+		// -- Check
+		assert!(dir.join(".events.ndjson.lock").is_file());
+		assert_eq!(content_while_locked, "", "the appenders must wait for the sibling lock");
+		assert_eq!(fs::read_to_string(&file)?, "{\"id\":1}\n{\"id\":2}\n{\"id\":3}\n");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
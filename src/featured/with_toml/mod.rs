@@ -0,0 +1,9 @@
+// region:    --- Modules
+
+mod load_save;
+mod toml_doc;
+
+pub use load_save::*;
+pub use toml_doc::*;
+
+// endregion: --- Modules
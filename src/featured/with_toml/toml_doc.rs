@@ -0,0 +1,224 @@
+use crate::error::{Cause, PathAndCause};
+use crate::{Error, Result, read_to_string, write_atomic};
+use core::fmt;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, InlineTable, Item, Table, Value};
+
+/// A TOML document loaded for editing, which preserves comments, key order, and formatting
+/// (backed by `toml_edit::DocumentMut`).
+///
+/// Keys are addressed by dotted key path (e.g., `"server.http.port"`).
+/// Note: Keys containing a `.` cannot be addressed by key path (use [`TomlDoc::doc_mut`]).
+#[derive(Debug, Clone)]
+pub struct TomlDoc {
+	path: PathBuf,
+	doc: DocumentMut,
+}
+
+/// Loads a TOML file as a [`TomlDoc`] for editing.
+pub fn load_toml_doc(file_path: impl AsRef<Path>) -> Result<TomlDoc> {
+	let file_path = file_path.as_ref();
+	let content = read_to_string(file_path)?;
+
+	let doc = content
+		.parse::<DocumentMut>()
+		.map_err(|e| Error::TomlCantRead((file_path, e).into()))?;
+
+	Ok(TomlDoc {
+		path: file_path.to_path_buf(),
+		doc,
+	})
+}
+
+/// Saves the [`TomlDoc`] to `file_path` (atomically, see [`write_atomic`]).
+pub fn save_toml_doc(file_path: impl AsRef<Path>, toml_doc: &TomlDoc) -> Result<()> {
+	let file_path = file_path.as_ref();
+	write_atomic(file_path, toml_doc.doc.to_string())
+}
+
+/// Getters
+impl TomlDoc {
+	/// The path the document was loaded from.
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Returns the item at the dotted `key_path`, if present.
+	pub fn get(&self, key_path: &str) -> Option<&Item> {
+		let mut item = self.doc.as_item();
+		for key in key_path.split('.') {
+			item = item.get(key)?;
+		}
+		Some(item)
+	}
+
+	pub fn doc(&self) -> &DocumentMut {
+		&self.doc
+	}
+
+	pub fn doc_mut(&mut self) -> &mut DocumentMut {
+		&mut self.doc
+	}
+}
+
+/// Setters
+impl TomlDoc {
+	/// Sets the value at the dotted `key_path`, creating the missing parent tables.
+	///
+	/// When a value already exists, its surrounding formatting (e.g., a trailing comment) is kept.
+	///
+	/// Returns a `Error::TomlCantWrite` if a parent of the key path is not a table.
+	pub fn set(&mut self, key_path: &str, value: impl Into<Value>) -> Result<()> {
+		let (parent_keys, last_key) = split_key_path(key_path);
+
+		let parent = self.get_or_create_parent(key_path, &parent_keys)?;
+
+		let mut value = value.into();
+		match parent.get_mut(last_key) {
+			// update in place to keep the key formatting and comments
+			Some(Item::Value(existing)) => {
+				*value.decor_mut() = existing.decor().clone();
+				*existing = value;
+			}
+			_ => {
+				parent.insert(last_key, Item::Value(value));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Removes the item at the dotted `key_path`, returning it if it was present.
+	pub fn remove(&mut self, key_path: &str) -> Option<Item> {
+		let (parent_keys, last_key) = split_key_path(key_path);
+
+		let mut item = self.doc.as_item_mut();
+		for key in parent_keys {
+			item = item.get_mut(key)?;
+		}
+
+		item.as_table_like_mut()?.remove(last_key)
+	}
+
+	/// Saves the document back to the path it was loaded from.
+	pub fn save(&self) -> Result<()> {
+		save_toml_doc(&self.path, self)
+	}
+}
+
+/// Private
+impl TomlDoc {
+	fn get_or_create_parent(&mut self, key_path: &str, parent_keys: &[&str]) -> Result<&mut dyn toml_edit::TableLike> {
+		let path = &self.path;
+		let not_a_table_err = |key: &str| {
+			Error::TomlCantWrite(PathAndCause {
+				path: path.to_string_lossy().to_string(),
+				cause: Cause::Custom(format!("Cannot set '{key_path}' because '{key}' is not a table")),
+			})
+		};
+
+		let mut item = self.doc.as_item_mut();
+		for &key in parent_keys {
+			let is_inline = item.is_inline_table();
+			let table = item.as_table_like_mut().ok_or_else(|| not_a_table_err(key))?;
+
+			if table.get(key).is_none() {
+				let new_item = if is_inline {
+					Item::Value(Value::InlineTable(InlineTable::new()))
+				} else {
+					let mut new_table = Table::new();
+					new_table.set_implicit(true);
+					Item::Table(new_table)
+				};
+				table.insert(key, new_item);
+			}
+
+			item = table.get_mut(key).ok_or_else(|| not_a_table_err(key))?;
+		}
+
+		item.as_table_like_mut().ok_or_else(|| not_a_table_err(key_path))
+	}
+}
+
+// region:    --- Std Traits Impls
+
+impl fmt::Display for TomlDoc {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.doc)
+	}
+}
+
+// endregion: --- Std Traits Impls
+
+// region:    --- Support
+
+/// Splits `a.b.c` into (["a", "b"], "c")
+fn split_key_path(key_path: &str) -> (Vec<&str>, &str) {
+	let mut keys: Vec<&str> = key_path.split('.').collect();
+	// NOTE: split always returns at least one element
+	let last_key = keys.pop().unwrap_or_default();
+	(keys, last_key)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_file_dir;
+	use std::fs;
+
+	#[test]
+	fn test_featured_toml_doc_set_remove_preserve() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/toml-doc/config.toml");
+		ensure_file_dir(file)?;
+		fs::write(
+			file,
+			"# My config\ntitle = \"app\" # the title\n\n[server]\n# the port\nport = 8080\nhost = \"localhost\"\n",
+		)?;
+
+		// -- Exec
+		let mut doc = load_toml_doc(file)?;
+		doc.set("server.port", 9090)?;
+		doc.set("title", "new-app")?;
+		doc.set("db.pool.max", 5)?;
+		let removed = doc.remove("server.host");
+		doc.save()?;
+
+		// -- Check
+		assert!(removed.is_some());
+		let content = fs::read_to_string(file)?;
+		assert_eq!(
+			content,
+			"# My config\ntitle = \"new-app\" # the title\n\n[server]\n# the port\nport = 9090\n\n[db.pool]\nmax = 5\n"
+		);
+		let doc = load_toml_doc(file)?;
+		assert_eq!(doc.get("db.pool.max").and_then(|v| v.as_integer()), Some(5));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_featured_toml_doc_set_not_a_table() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/toml-doc-err/config.toml");
+		ensure_file_dir(file)?;
+		fs::write(file, "title = \"app\"\n")?;
+		let mut doc = load_toml_doc(file)?;
+
+		// -- Exec
+		let res = doc.set("title.sub", 1);
+
+		// -- Check
+		assert!(matches!(res, Err(Error::TomlCantWrite(_))));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
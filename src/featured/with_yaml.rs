@@ -0,0 +1,82 @@
+use crate::file::create_file;
+use crate::{Error, Result, get_buf_reader};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+pub fn load_yaml<T>(file_path: impl AsRef<Path>) -> Result<T>
+where
+	T: serde::de::DeserializeOwned,
+{
+	let file_path = file_path.as_ref();
+
+	let buf_reader = get_buf_reader(file_path)?;
+	let val = serde_yaml::from_reader(buf_reader).map_err(|e| Error::YamlCantRead((file_path, e).into()))?;
+
+	Ok(val)
+}
+
+pub fn save_yaml<T>(file_path: impl AsRef<Path>, data: &T) -> Result<()>
+where
+	T: serde::Serialize,
+{
+	let file_path = file_path.as_ref();
+
+	let file = create_file(file_path)?;
+	let mut writer = BufWriter::new(file);
+	serde_yaml::to_writer(&mut writer, data).map_err(|e| Error::YamlCantWrite((file_path, e).into()))?;
+	writer.flush().map_err(|e| Error::YamlCantWrite((file_path, e).into()))?;
+
+	Ok(())
+}
+
+/// Returns an iterator over each YAML document (separated by `---`) of a multi-document file,
+/// deserialized as `T` (e.g., `serde_yaml::Value`, `serde_json::Value`, or a struct).
+///
+/// Note: A file without any `---` separator is a single document.
+///
+/// Note: Unlike `stream_ndjson`, this does not stream from the file, as the YAML deserializer
+///       reads the whole input into memory first (only the documents are deserialized one at a time).
+pub fn stream_yaml_docs<T>(file_path: impl AsRef<Path>) -> Result<impl Iterator<Item = Result<T>>>
+where
+	T: serde::de::DeserializeOwned,
+{
+	let file_path = file_path.as_ref();
+	let buf_reader = get_buf_reader(file_path)?;
+
+	let path_for_err = file_path.to_path_buf();
+	let iter = serde_yaml::Deserializer::from_reader(buf_reader)
+		.map(move |doc| T::deserialize(doc).map_err(|e| Error::YamlCantRead((path_for_err.as_path(), e).into())));
+
+	Ok(iter)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_file_dir;
+	use serde_yaml::Value;
+	use std::fs;
+
+	#[test]
+	fn test_featured_yaml_stream_yaml_docs_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		let file = Path::new("target/tests-tmp/yaml-stream/docs.yaml");
+		ensure_file_dir(file)?;
+		fs::write(file, "kind: Service\nname: one\n---\nkind: Deployment\nname: two\n")?;
+
+		// -- Exec
+		let docs = stream_yaml_docs::<Value>(file)?.collect::<crate::Result<Vec<Value>>>()?;
+
+		// -- Check
+		let names: Vec<&str> = docs.iter().filter_map(|d| d["name"].as_str()).collect();
+		assert_eq!(names, ["one", "two"]);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
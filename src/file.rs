@@ -1,7 +1,10 @@
 use crate::{Error, Result};
 use std::fs::{self, File};
-use std::io::{BufReader, BufWriter};
-use std::path::Path;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static ATOMIC_TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 pub fn create_file(file_path: impl AsRef<Path>) -> Result<File> {
 	let file_path = file_path.as_ref();
@@ -41,3 +44,53 @@ pub fn get_buf_writer(file_path: impl AsRef<Path>) -> Result<BufWriter<File>> {
 
 	Ok(BufWriter::new(file))
 }
+
+/// Writes `content` to `file_path` atomically.
+///
+/// The content is written and synced to a temporary sibling file, which is then renamed to `file_path`.
+/// On failure, the temporary file is removed and `file_path` is left untouched (never partially written).
+///
+/// Note: When `file_path` already exists, its permissions are carried over to the new file.
+pub fn write_atomic(file_path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> Result<()> {
+	let file_path = file_path.as_ref();
+	let tmp_path = atomic_tmp_path(file_path)?;
+
+	let res = write_and_rename(file_path, &tmp_path, content.as_ref());
+	if res.is_err() {
+		let _ = fs::remove_file(&tmp_path);
+	}
+
+	res
+}
+
+// region:    --- Support
+
+/// Returns a unique temporary sibling path for `file_path` (e.g., `dir/.name.tmp-123-0`)
+fn atomic_tmp_path(file_path: &Path) -> Result<PathBuf> {
+	let file_name = file_path
+		.file_name()
+		.ok_or_else(|| Error::FileCantCreate((file_path, std::io::Error::other("path has no file name")).into()))?;
+
+	let counter = ATOMIC_TMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+	let tmp_name = format!(".{}.tmp-{}-{counter}", file_name.to_string_lossy(), std::process::id());
+
+	Ok(file_path.with_file_name(tmp_name))
+}
+
+fn write_and_rename(file_path: &Path, tmp_path: &Path, content: &[u8]) -> Result<()> {
+	let mut file = File::create(tmp_path).map_err(|e| Error::FileCantCreate((tmp_path, e).into()))?;
+	file.write_all(content)
+		.map_err(|e| Error::FileCantWrite((tmp_path, e).into()))?;
+	file.sync_all().map_err(|e| Error::FileCantWrite((tmp_path, e).into()))?;
+
+	if let Ok(metadata) = fs::metadata(file_path) {
+		fs::set_permissions(tmp_path, metadata.permissions())
+			.map_err(|e| Error::FileCantWrite((tmp_path, e).into()))?;
+	}
+
+	fs::rename(tmp_path, file_path).map_err(|e| Error::FileCantWrite((file_path, e).into()))?;
+
+	Ok(())
+}
+
+// endregion: --- Support
@@ -6,10 +6,13 @@ mod error;
 mod featured;
 mod file;
 mod list;
+mod lock;
 mod reshape;
 mod safer;
+mod search;
 mod span;
 mod spath;
+mod temp;
 mod watch;
 
 pub use self::error::{Error, Result};
@@ -20,10 +23,13 @@ pub use common::*;
 pub use dir::*;
 pub use file::*;
 pub use list::*;
+pub use lock::*;
 pub use reshape::*;
 pub use safer::*;
+pub use search::*;
 pub use span::*;
 pub use spath::*;
+pub use temp::*;
 pub use watch::*;
 
 #[allow(unused)]
@@ -3,10 +3,10 @@ use crate::{ListOptions, Result, SPath, get_depth};
 use std::collections::HashSet;
 use std::path::Path;
 use std::sync::Arc;
-use walkdir::WalkDir;
+use walkdir::{DirEntry, WalkDir};
 
 pub struct GlobsFileIter {
-	inner: Box<dyn Iterator<Item = SPath>>,
+	inner: Box<dyn Iterator<Item = (SPath, DirEntry)>>,
 }
 
 impl GlobsFileIter {
@@ -90,7 +90,7 @@ impl GlobsFileIter {
 			.transpose()?;
 
 		// For each group, create a WalkDir iterator with its own base and globset
-		let mut group_iterators: Vec<Box<dyn Iterator<Item = SPath>>> = Vec::new();
+		let mut group_iterators: Vec<Box<dyn Iterator<Item = (SPath, DirEntry)>>> = Vec::new();
 
 		let max_depth = list_options.and_then(|o| o.depth);
 
@@ -152,13 +152,14 @@ impl GlobsFileIter {
 				})
 				.filter_map(|entry| entry.ok())
 				.filter(|entry| entry.file_type().is_file())
-				.filter_map(SPath::from_walkdir_entry_ok);
+				// NOTE: The walkdir entry is kept (e.g., for its metadata, see `into_entries`)
+				.filter_map(|entry| Some((SPath::from_std_path(entry.path()).ok()?, entry)));
 
 			let exclude_globs_set_clone = exclude_globs_set.clone();
 			let main_base_clone = main_base.clone();
 			let base_clone = group_base.clone();
 
-			let iter = iter.filter(move |sfile| {
+			let iter = iter.filter(move |(sfile, _)| {
 				// First check if the file should be excluded by the exclude_globs
 				if let Some(exclude) = exclude_globs_set_clone.as_ref() {
 					// Use appropriate path based on relative_glob setting
@@ -187,14 +188,14 @@ impl GlobsFileIter {
 
 		// Combine all group iterators into one combined iterator
 		let combined_iter = group_iterators.into_iter().fold(
-			Box::new(std::iter::empty()) as Box<dyn Iterator<Item = SPath>>,
-			|acc, iter| Box::new(acc.chain(iter)) as Box<dyn Iterator<Item = SPath>>,
+			Box::new(std::iter::empty()) as Box<dyn Iterator<Item = (SPath, DirEntry)>>,
+			|acc, iter| Box::new(acc.chain(iter)) as Box<dyn Iterator<Item = (SPath, DirEntry)>>,
 		);
 
 		// Use scan to keep track of absolute file paths and remove duplicates.
 		let dedup_iter = combined_iter
 			.scan(HashSet::<SPath>::new(), |seen, file| {
-				let path = file.clone();
+				let path = file.0.clone();
 				if seen.insert(path) {
 					Some(Some(file))
 				} else {
@@ -209,10 +210,17 @@ impl GlobsFileIter {
 	}
 }
 
+impl GlobsFileIter {
+	/// Returns the files with their walkdir entry (e.g., to get the metadata without another lookup of the path).
+	pub(crate) fn into_entries(self) -> impl Iterator<Item = (SPath, DirEntry)> {
+		self.inner
+	}
+}
+
 impl Iterator for GlobsFileIter {
 	type Item = SPath;
 	fn next(&mut self) -> Option<Self::Item> {
-		self.inner.next()
+		self.inner.next().map(|(file, _)| file)
 	}
 }
 
@@ -0,0 +1,214 @@
+use crate::{Error, Result, ensure_dir, write_atomic};
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+
+/// The name of the lock file created in the directory by [`with_dir_lock`].
+pub const DIR_LOCK_FILE_NAME: &str = ".simple-fs.lock";
+
+/// An advisory lock on a file (`flock` on Linux/macOS, `LockFileEx` on Windows),
+/// released when dropped.
+///
+/// Note: Advisory locks only coordinate processes that also take the lock;
+///       they do not prevent other writes.
+#[derive(Debug)]
+pub struct FileLock {
+	path: PathBuf,
+	file: File,
+	exclusive: bool,
+}
+
+impl FileLock {
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	pub fn is_exclusive(&self) -> bool {
+		self.exclusive
+	}
+
+	/// The locked file handle (e.g., to write while holding the lock).
+	pub fn file(&self) -> &File {
+		&self.file
+	}
+}
+
+impl Drop for FileLock {
+	fn drop(&mut self) {
+		// NOTE: Closing the file releases the lock as well, this just makes it explicit.
+		let _ = self.file.unlock();
+	}
+}
+
+/// Locks `path`, blocking until the lock is acquired.
+/// The file is created if it does not exist (its content is never truncated).
+///
+/// - `exclusive: true` for a write lock (one holder),
+/// - `exclusive: false` for a shared/read lock (many holders, no exclusive one).
+pub fn lock_file(path: impl AsRef<Path>, exclusive: bool) -> Result<FileLock> {
+	let path = path.as_ref();
+	let file = open_lock_file(path)?;
+
+	let res = if exclusive { file.lock() } else { file.lock_shared() };
+	res.map_err(|e| Error::FileCantLock((path, e).into()))?;
+
+	Ok(FileLock {
+		path: path.to_path_buf(),
+		file,
+		exclusive,
+	})
+}
+
+/// Same as [`lock_file`] but does not block.
+/// Returns `Ok(None)` when the lock is currently held by someone else.
+pub fn try_lock_file(path: impl AsRef<Path>, exclusive: bool) -> Result<Option<FileLock>> {
+	let path = path.as_ref();
+	let file = open_lock_file(path)?;
+
+	let res = if exclusive {
+		file.try_lock()
+	} else {
+		file.try_lock_shared()
+	};
+	match res {
+		Ok(()) => Ok(Some(FileLock {
+			path: path.to_path_buf(),
+			file,
+			exclusive,
+		})),
+		Err(TryLockError::WouldBlock) => Ok(None),
+		Err(TryLockError::Error(e)) => Err(Error::FileCantLock((path, e).into())),
+	}
+}
+
+/// Runs `f` while holding an exclusive lock on the `DIR_LOCK_FILE_NAME` file of `dir`
+/// (e.g., for tools sharing a cache directory). The directory is created if missing.
+///
+/// The lock is released when `f` returns (the lock file is left in place).
+pub fn with_dir_lock<R>(dir: impl AsRef<Path>, f: impl FnOnce() -> Result<R>) -> Result<R> {
+	let dir = dir.as_ref();
+	ensure_dir(dir)?;
+
+	let _lock = lock_file(dir.join(DIR_LOCK_FILE_NAME), true)?;
+
+	f()
+}
+
+/// Same as [`write_atomic`], but holding an exclusive lock on the sibling lock file
+/// (e.g., `dir/.name.json.lock`, see [`lock_file_sibling`]).
+pub fn write_atomic_locked(file_path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> Result<()> {
+	let file_path = file_path.as_ref();
+	let _lock = lock_file_sibling(file_path, true)?;
+	write_atomic(file_path, content)
+}
+
+/// Locks the sibling lock file of `file_path` (e.g., `dir/name.json` → `dir/.name.json.lock`).
+///
+/// This is the lock used by the `_locked` writers. A sibling file is used (rather than `file_path` itself)
+/// because atomic saves replace the file, and a lock on the replaced file would not be seen by the next writer.
+pub fn lock_file_sibling(file_path: impl AsRef<Path>, exclusive: bool) -> Result<FileLock> {
+	let file_path = file_path.as_ref();
+	let file_name = file_path
+		.file_name()
+		.ok_or_else(|| Error::PathHasNoFileName(file_path.to_string_lossy().to_string()))?;
+
+	let lock_path = file_path.with_file_name(format!(".{}.lock", file_name.to_string_lossy()));
+
+	lock_file(lock_path, exclusive)
+}
+
+// region:    --- Support
+
+fn open_lock_file(path: &Path) -> Result<File> {
+	OpenOptions::new()
+		.read(true)
+		.write(true)
+		.create(true)
+		.truncate(false)
+		.open(path)
+		.map_err(|e| Error::FileCantOpen((path, e).into()))
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_lock_try_lock_file_exclusive() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/lock-try");
+		ensure_dir(dir)?;
+		let path = dir.join("data.lock");
+
+		// -- Exec & Check
+		let lock = lock_file(&path, true)?;
+		assert!(try_lock_file(&path, false)?.is_none());
+		assert!(try_lock_file(&path, true)?.is_none());
+		drop(lock);
+
+		let shared = try_lock_file(&path, false)?.ok_or("should lock shared")?;
+		assert!(try_lock_file(&path, false)?.is_some());
+		assert!(try_lock_file(&path, true)?.is_none());
+		drop(shared);
+
+		assert!(try_lock_file(&path, true)?.is_some());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_lock_with_dir_lock_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/lock-dir");
+
+		// -- Exec
+		let value = with_dir_lock(dir, || {
+			// the lock is held during the closure
+			let other = try_lock_file(dir.join(DIR_LOCK_FILE_NAME), true)?;
+			Ok(other.is_none())
+		})?;
+
+		// -- Check
+		assert!(value);
+		assert!(try_lock_file(dir.join(DIR_LOCK_FILE_NAME), true)?.is_some());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_lock_write_atomic_locked_uses_sibling_lock() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = Path::new("target/tests-tmp/lock-write-atomic");
+		ensure_dir(dir)?;
+		let file = dir.join("data.json");
+		std::fs::write(&file, "before")?;
+
+		// -- Exec
+		let lock = lock_file_sibling(&file, true)?;
+		let writer = {
+			let file = file.clone();
+			std::thread::spawn(move || write_atomic_locked(&file, "after"))
+		};
+		std::thread::sleep(std::time::Duration::from_millis(100));
+		let content_while_locked = std::fs::read_to_string(&file)?;
+		drop(lock);
+		writer.join().map_err(|_| "writer thread panicked")??;
+
+		// -- Check
+		assert_eq!(lock_file_sibling(&file, false)?.path(), dir.join(".data.json.lock"));
+		assert_eq!(
+			content_while_locked, "before",
+			"the writer must wait for the sibling lock"
+		);
+		assert_eq!(std::fs::read_to_string(&file)?, "after");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
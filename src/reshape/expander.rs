@@ -0,0 +1,182 @@
+use crate::{Error, Result, home_dir};
+use std::collections::HashMap;
+
+/// Options of `SPath::expand_with(...)`.
+#[derive(Debug, Clone, Default)]
+pub struct ExpandOptions<'a> {
+	/// The variables for `$VAR` / `${VAR}`. When `None`, the environment variables are used.
+	pub vars: Option<&'a HashMap<String, String>>,
+
+	/// When true, an undefined variable is an `Error::PathExpandVarUndefined`.
+	/// Otherwise, it is kept as is (e.g., `$UNDEFINED`).
+	pub strict: bool,
+}
+
+// region:    --- Froms
+
+impl From<()> for ExpandOptions<'_> {
+	fn from(_: ()) -> Self {
+		Self::default()
+	}
+}
+
+impl<'a> From<&'a HashMap<String, String>> for ExpandOptions<'a> {
+	fn from(vars: &'a HashMap<String, String>) -> Self {
+		Self {
+			vars: Some(vars),
+			..Default::default()
+		}
+	}
+}
+
+// endregion: --- Froms
+
+// region:    --- Fluent API
+
+impl<'a> ExpandOptions<'a> {
+	pub fn with_vars(mut self, vars: &'a HashMap<String, String>) -> Self {
+		self.vars = Some(vars);
+		self
+	}
+
+	pub fn with_strict(mut self, val: bool) -> Self {
+		self.strict = val;
+		self
+	}
+}
+
+// endregion: --- Fluent API
+
+// region:    --- Expand
+
+/// Expands a leading `~` (only `~` or `~/...`) with the home directory,
+/// and the `$VAR` / `${VAR}` variables (names are `[A-Za-z_][A-Za-z0-9_]*`).
+///
+/// Note: A `$` not followed by a variable name (or an unclosed `${`) is kept as is.
+pub(crate) fn expand_path_str(path: &str, options: &ExpandOptions) -> Result<String> {
+	let mut res = String::with_capacity(path.len());
+
+	// -- Expand the leading ~
+	let rest = if path == "~" || path.starts_with("~/") {
+		res.push_str(home_dir()?.as_str());
+		&path[1..]
+	} else {
+		path
+	};
+
+	// -- Expand the variables
+	let mut chars = rest.char_indices().peekable();
+	while let Some((idx, c)) = chars.next() {
+		if c != '$' {
+			res.push(c);
+			continue;
+		}
+
+		let after = &rest[idx + 1..];
+		let (name, consumed) = if let Some(braced) = after.strip_prefix('{') {
+			match braced.find('}') {
+				Some(end) if is_var_name(&braced[..end]) => (&braced[..end], end + 2),
+				_ => ("", 0),
+			}
+		} else {
+			let len = after
+				.char_indices()
+				.find(|(i, c)| !(c.is_ascii_alphanumeric() || *c == '_') || (*i == 0 && c.is_ascii_digit()))
+				.map(|(i, _)| i)
+				.unwrap_or(after.len());
+			(&after[..len], len)
+		};
+
+		// Not a variable, keep the `$` as is
+		if name.is_empty() {
+			res.push(c);
+			continue;
+		}
+
+		match lookup_var(name, options) {
+			Some(value) => res.push_str(&value),
+			None if options.strict => {
+				return Err(Error::PathExpandVarUndefined {
+					path: path.to_string(),
+					var: name.to_string(),
+				});
+			}
+			None => res.push_str(&rest[idx..idx + 1 + consumed]),
+		}
+
+		// Skip the consumed characters (all ASCII, so one char per byte)
+		for _ in 0..consumed {
+			chars.next();
+		}
+	}
+
+	Ok(res)
+}
+
+fn lookup_var(name: &str, options: &ExpandOptions) -> Option<String> {
+	match options.vars {
+		Some(vars) => vars.get(name).cloned(),
+		None => std::env::var(name).ok(),
+	}
+}
+
+fn is_var_name(name: &str) -> bool {
+	let mut chars = name.chars();
+	match chars.next() {
+		Some(c) if c.is_ascii_alphabetic() || c == '_' => chars.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+		_ => false,
+	}
+}
+
+// endregion: --- Expand
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_reshape_expand_path_str_vars() -> Result<()> {
+		// -- Setup & Fixtures
+		let vars: HashMap<String, String> = [("PROJECT", "demo"), ("OUT", "out")]
+			.into_iter()
+			.map(|(k, v)| (k.to_string(), v.to_string()))
+			.collect();
+		let options = ExpandOptions::from(&vars);
+		let cases = [
+			("data/${PROJECT}/$OUT", "data/demo/out"),
+			("data/$PROJECT-v1/file.txt", "data/demo-v1/file.txt"),
+			("data/$UNDEFINED/${UNDEFINED}", "data/$UNDEFINED/${UNDEFINED}"),
+			("price/$5/${unclosed", "price/$5/${unclosed"),
+			("not~/home", "not~/home"),
+		];
+
+		// -- Exec & Check
+		for (path, expected) in cases {
+			assert_eq!(expand_path_str(path, &options)?, expected, "for '{path}'");
+		}
+		let strict = expand_path_str("data/${UNDEFINED}", &options.clone().with_strict(true));
+		assert!(matches!(strict, Err(Error::PathExpandVarUndefined { var, .. }) if var == "UNDEFINED"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_reshape_expand_path_str_home() -> Result<()> {
+		// -- Setup & Fixtures
+		let home = home_dir()?;
+
+		// -- Exec
+		let expanded = expand_path_str("~/data", &ExpandOptions::default())?;
+
+		// -- Check
+		assert_eq!(expanded, format!("{home}/data"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,9 +1,12 @@
 // region:    --- modules
 
 mod collapser;
+mod expander;
 mod normalizer;
 
 pub use collapser::*;
+pub use expander::ExpandOptions;
+pub(crate) use expander::expand_path_str;
 pub use normalizer::*;
 
 // endregion: --- modules
@@ -2,13 +2,19 @@
 
 mod safer_remove_impl;
 mod safer_remove_options;
+mod safer_remove_report;
 mod safer_trash_impl;
 mod safer_trash_options;
 mod support;
+#[cfg(all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android")))]
+mod trash_restore;
 
 pub use safer_remove_impl::*;
 pub use safer_remove_options::*;
+pub use safer_remove_report::*;
 pub use safer_trash_impl::*;
 pub use safer_trash_options::*;
+#[cfg(all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android")))]
+pub use trash_restore::*;
 
 // endregion: --- Modules
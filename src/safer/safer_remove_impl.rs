@@ -1,8 +1,13 @@
-use crate::SPath;
 use crate::error::{Cause, PathAndCause};
-use crate::safer::{SaferRemoveOptions, support};
+use crate::safer::{SaferRemoveGlobsReport, SaferRemoveOptions, SaferRemoveReport, support};
 use crate::{Error, Result};
+use crate::{ListOptions, SPath, list_files};
+use std::collections::HashSet;
 use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+use walkdir::WalkDir;
 
 /// Safely deletes a directory if it passes safety checks.
 ///
@@ -10,23 +15,49 @@ use std::fs;
 /// - If `restrict_to_current_dir` is true, the directory path must be below the current directory
 /// - If `must_contain_any` is set, the path must contain at least one of the specified patterns
 /// - If `must_contain_all` is set, the path must contain all of the specified patterns
+/// - If `must_match_any_glob` is set, the path must match at least one of the specified globs
+/// - If `allowed_roots` is set, the resolved path must be below at least one of the roots
+/// - The filesystem root, the home directory (and its parents), and mount points are always refused
 ///
-/// Returns Ok(true) if the directory was deleted, Ok(false) if it didn't exist.
+/// Returns Ok(true) if the directory was deleted (or would be, with `dry_run`), Ok(false) if it didn't exist.
+/// Use `safer_remove_dir_report` to get what was (or would be) removed.
 /// Returns an error if safety checks fail or deletion fails.
 pub fn safer_remove_dir<'a>(dir_path: &SPath, options: impl Into<SaferRemoveOptions<'a>>) -> Result<bool> {
-	let options = options.into();
-
 	// If path doesn't exist, just return false
 	if !dir_path.exists() {
 		return Ok(false);
 	}
 
-	let causes = support::check_path_safety_causes(
-		dir_path,
-		options.restrict_to_current_dir,
-		options.must_contain_any,
-		options.must_contain_all,
-	)?;
+	safer_remove_dir_report(dir_path, options)?;
+
+	Ok(true)
+}
+
+/// Same as `safer_remove_dir`, but returns the report of what was removed
+/// (or, with `dry_run`, the plan of what would be removed).
+///
+/// The `max_files` and `max_bytes` ceilings are checked on the plan, before anything is removed.
+/// A directory with an entry on another file system (e.g., a mount point below it) is refused.
+///
+/// A failed removal does not stop the others, and all the paths which could not be removed
+/// are returned in an `Error::DirNotFullyRemoved` (see also `clear_readonly` and `max_retries`).
+///
+/// Returns an empty report if the directory does not exist.
+pub fn safer_remove_dir_report<'a>(
+	dir_path: &SPath,
+	options: impl Into<SaferRemoveOptions<'a>>,
+) -> Result<SaferRemoveReport> {
+	let options = options.into();
+
+	// If path doesn't exist, nothing to plan or remove
+	if !dir_path.exists() {
+		return Ok(SaferRemoveReport {
+			dry_run: options.dry_run,
+			..Default::default()
+		});
+	}
+
+	let causes = support::check_path_safety_causes(dir_path, &(&options).into())?;
 
 	if !causes.is_empty() {
 		return Err(Error::DirNotSafeToRemove(PathAndCause {
@@ -35,15 +66,52 @@ pub fn safer_remove_dir<'a>(dir_path: &SPath, options: impl Into<SaferRemoveOpti
 		}));
 	}
 
-	// Perform the deletion
-	fs::remove_dir_all(dir_path.as_std_path()).map_err(|e| {
-		Error::DirNotSafeToRemove(PathAndCause {
+	// -- Plan & check the ceilings
+	let mut report = plan_dir_removal(dir_path)?;
+	report.dry_run = options.dry_run;
+
+	let mut ceiling_causes = Vec::new();
+	if let Some(max_files) = options.max_files
+		&& report.file_count() > max_files
+	{
+		ceiling_causes.push(format!("has {} files (max_files: {max_files})", report.file_count()));
+	}
+	if let Some(max_bytes) = options.max_bytes
+		&& report.total_bytes > max_bytes
+	{
+		ceiling_causes.push(format!("has {} bytes (max_bytes: {max_bytes})", report.total_bytes));
+	}
+	if !ceiling_causes.is_empty() {
+		return Err(Error::DirNotSafeToRemove(PathAndCause {
 			path: dir_path.to_string(),
-			cause: Cause::Io(Box::new(e)),
-		})
-	})?;
+			cause: Cause::Custom(format!("Ceiling exceeded: {}", ceiling_causes.join("; "))),
+		}));
+	}
 
-	Ok(true)
+	if options.dry_run {
+		return Ok(report);
+	}
+
+	// -- Perform the deletion (in plan order, contents first)
+	let failed = remove_planned(&report, |path, is_dir| {
+		ensure_parent_unchanged(path, dir_path)?;
+		remove_with_retry(path, is_dir, Some(dir_path), &options)
+	});
+
+	if !failed.is_empty() {
+		return Err(Error::DirNotFullyRemoved {
+			path: dir_path.to_string(),
+			failed: failed
+				.into_iter()
+				.map(|(path, err)| PathAndCause {
+					path: path.to_string(),
+					cause: Cause::Io(Box::new(err)),
+				})
+				.collect(),
+		});
+	}
+
+	Ok(report)
 }
 
 /// Safely deletes a file if it passes safety checks.
@@ -52,8 +120,11 @@ pub fn safer_remove_dir<'a>(dir_path: &SPath, options: impl Into<SaferRemoveOpti
 /// - If `restrict_to_current_dir` is true, the file path must be below the current directory
 /// - If `must_contain_any` is set, the path must contain at least one of the specified patterns
 /// - If `must_contain_all` is set, the path must contain all of the specified patterns
+/// - If `must_match_any_glob` is set, the path must match at least one of the specified globs
+/// - If `allowed_roots` is set, the resolved path must be below at least one of the roots
+/// - The filesystem root, the home directory (and its parents), and mount points are always refused
 ///
-/// Returns Ok(true) if the file was deleted, Ok(false) if it didn't exist.
+/// Returns Ok(true) if the file was deleted (or would be, with `dry_run`), Ok(false) if it didn't exist.
 /// Returns an error if safety checks fail or deletion fails.
 pub fn safer_remove_file<'a>(file_path: &SPath, options: impl Into<SaferRemoveOptions<'a>>) -> Result<bool> {
 	let options = options.into();
@@ -63,12 +134,7 @@ pub fn safer_remove_file<'a>(file_path: &SPath, options: impl Into<SaferRemoveOp
 		return Ok(false);
 	}
 
-	let causes = support::check_path_safety_causes(
-		file_path,
-		options.restrict_to_current_dir,
-		options.must_contain_any,
-		options.must_contain_all,
-	)?;
+	let causes = support::check_path_safety_causes(file_path, &(&options).into())?;
 
 	if !causes.is_empty() {
 		return Err(Error::FileNotSafeToRemove(PathAndCause {
@@ -77,8 +143,12 @@ pub fn safer_remove_file<'a>(file_path: &SPath, options: impl Into<SaferRemoveOp
 		}));
 	}
 
+	if options.dry_run {
+		return Ok(true);
+	}
+
 	// Perform the deletion
-	fs::remove_file(file_path.as_std_path()).map_err(|e| {
+	remove_with_retry(file_path, false, None, &options).map_err(|e| {
 		Error::FileNotSafeToRemove(PathAndCause {
 			path: file_path.to_string(),
 			cause: Cause::Io(Box::new(e)),
@@ -88,5 +158,528 @@ pub fn safer_remove_file<'a>(file_path: &SPath, options: impl Into<SaferRemoveOp
 	Ok(true)
 }
 
+/// Removes the files of `dir` matching the `include_globs` (same listing as `list_files`),
+/// then prunes the directories left empty (below `dir`, which is never removed).
+///
+/// The whole batch is validated up front (safety checks, `max_files`, `max_bytes`),
+/// and nothing is removed if any file fails.
+///
+/// Returns the counts per outcome, or an `Error::DirNotFullyRemoved` with every file which could not be removed.
+pub fn safer_remove_globs<'a>(
+	dir: impl AsRef<Path>,
+	include_globs: Option<&[&str]>,
+	list_options: Option<ListOptions<'_>>,
+	safer_options: impl Into<SaferRemoveOptions<'a>>,
+) -> Result<SaferRemoveGlobsReport> {
+	let dir = SPath::from_std_path(dir)?;
+	let options = safer_options.into();
+	let rules: support::SafetyRules = (&options).into();
+	let rules = rules.compile()?;
+
+	let files = list_files(&dir, include_globs, list_options)?;
+
+	// -- Validate the whole batch
+	let mut unsafe_causes = Vec::new();
+	for file in files.iter() {
+		let causes = rules.check_causes(file)?;
+		if !causes.is_empty() {
+			unsafe_causes.push(format!("'{file}' ({})", causes.join(", ")));
+		}
+	}
+	if let Some(max_files) = options.max_files
+		&& files.len() > max_files
+	{
+		unsafe_causes.push(format!("has {} files (max_files: {max_files})", files.len()));
+	}
+	if let Some(max_bytes) = options.max_bytes {
+		let mut total_bytes = 0;
+		for file in files.iter() {
+			total_bytes += file.meta()?.size;
+		}
+		if total_bytes > max_bytes {
+			unsafe_causes.push(format!("has {total_bytes} bytes (max_bytes: {max_bytes})"));
+		}
+	}
+	if !unsafe_causes.is_empty() {
+		return Err(Error::DirNotSafeToRemove(PathAndCause {
+			path: dir.to_string(),
+			cause: Cause::Custom(format!("Safety check failed: {}", unsafe_causes.join("; "))),
+		}));
+	}
+
+	let mut report = SaferRemoveGlobsReport {
+		dry_run: options.dry_run,
+		..Default::default()
+	};
+
+	if options.dry_run {
+		report.removed_files = files.len();
+		return Ok(report);
+	}
+
+	// -- Remove the files
+	let mut parent_dirs: HashSet<SPath> = HashSet::new();
+	let mut failed = Vec::new();
+	for file in files.iter() {
+		match remove_with_retry(file, false, Some(&dir), &options) {
+			Ok(()) => report.removed_files += 1,
+			Err(err) if err.kind() == ErrorKind::NotFound => report.missing_files += 1,
+			Err(err) => failed.push(PathAndCause {
+				path: file.to_string(),
+				cause: Cause::Io(Box::new(err)),
+			}),
+		}
+		if let Some(parent) = file.parent() {
+			parent_dirs.insert(parent);
+		}
+	}
+
+	// -- Prune the empty directories (deepest first, up to but excluding `dir`)
+	let mut candidates: Vec<SPath> = Vec::new();
+	for parent in parent_dirs {
+		let mut current = Some(parent);
+		while let Some(candidate) = current {
+			if !candidate.starts_with(&dir) || candidate == dir || candidates.contains(&candidate) {
+				break;
+			}
+			current = candidate.parent();
+			candidates.push(candidate);
+		}
+	}
+	candidates.sort_by_key(|c| std::cmp::Reverse(c.as_std_path().components().count()));
+	for candidate in candidates {
+		let is_empty = fs::read_dir(candidate.as_std_path()).is_ok_and(|mut entries| entries.next().is_none());
+		if is_empty && fs::remove_dir(candidate.as_std_path()).is_ok() {
+			report.pruned_dirs += 1;
+		}
+	}
+
+	if !failed.is_empty() {
+		return Err(Error::DirNotFullyRemoved {
+			path: dir.to_string(),
+			failed,
+		});
+	}
+
+	Ok(report)
+}
+
 // region:    --- Support
+
+/// The first retry delay, doubled on each retry.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(10);
+
+/// Removes the file (or symlink) or empty directory, retrying with backoff
+/// (per `max_retries`) and clearing the read-only bits (per `clear_readonly`).
+///
+/// On unix, the parent directory is made writable only when strictly inside `tree_dir`
+/// (e.g., the removed directory, or the `dir` of `safer_remove_globs`), so the parent of the target is never changed.
+/// On other systems, the read-only attribute of the path itself is cleared.
+///
+/// A path which is already gone is a success (`NotFound` is returned only when missing from the start).
+fn remove_with_retry(
+	path: &SPath,
+	is_dir: bool,
+	tree_dir: Option<&SPath>,
+	options: &SaferRemoveOptions,
+) -> std::io::Result<()> {
+	if options.clear_readonly {
+		// NOTE: On unix, the removal is governed by the parent directory permissions
+		#[cfg(unix)]
+		if let (Some(parent), Some(tree_dir)) = (path.parent(), tree_dir)
+			&& parent.starts_with(tree_dir)
+			&& &parent != tree_dir
+		{
+			clear_readonly(&parent);
+		}
+		#[cfg(not(unix))]
+		{
+			let _ = tree_dir;
+			clear_readonly(path);
+		}
+	}
+
+	let mut attempt = 0;
+	loop {
+		let res = if is_dir {
+			fs::remove_dir(path.as_std_path())
+		} else {
+			fs::remove_file(path.as_std_path())
+		};
+
+		match res {
+			Ok(()) => return Ok(()),
+			Err(err) if err.kind() == ErrorKind::NotFound && attempt > 0 => return Ok(()),
+			Err(err) if err.kind() == ErrorKind::NotFound || attempt >= options.max_retries => return Err(err),
+			Err(_) => {
+				std::thread::sleep(RETRY_BASE_DELAY * 2u32.saturating_pow(attempt));
+				attempt += 1;
+			}
+		}
+	}
+}
+
+/// Removes the planned entries with `remove(path, is_dir)`: the files, then the directories (contents first).
+///
+/// NOTE: Continues on failure, so that every path which cannot be removed is returned with its own error
+///       (the ancestors of a failed path are not attempted, as they cannot be empty).
+fn remove_planned(
+	report: &SaferRemoveReport,
+	remove: impl Fn(&SPath, bool) -> std::io::Result<()>,
+) -> Vec<(SPath, std::io::Error)> {
+	let mut failed: Vec<(SPath, std::io::Error)> = Vec::new();
+	for file in report.files.iter() {
+		if let Err(err) = remove(file, false) {
+			failed.push((file.clone(), err));
+		}
+	}
+	for dir in report.dirs.iter() {
+		if failed.iter().any(|(path, _)| path.starts_with(dir)) {
+			continue;
+		}
+		if let Err(err) = remove(dir, true) {
+			failed.push((dir.clone(), err));
+		}
+	}
+	failed
+}
+
+/// Fails if the parent of the planned `path` (below `dir_path`) is no longer a directory
+/// of the same file system (e.g., swapped for a symlink since the plan), so the removal never goes through it.
+fn ensure_parent_unchanged(path: &SPath, dir_path: &SPath) -> std::io::Result<()> {
+	let Some(parent) = path.parent().filter(|parent| parent.starts_with(dir_path)) else {
+		return Ok(());
+	};
+
+	let parent_meta = fs::symlink_metadata(parent.as_std_path())?;
+	if !parent_meta.is_dir() {
+		return Err(std::io::Error::other(format!(
+			"parent '{parent}' is no longer a directory (changed since the plan)"
+		)));
+	}
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::MetadataExt;
+		if parent_meta.dev() != fs::symlink_metadata(dir_path.as_std_path())?.dev() {
+			return Err(std::io::Error::other(format!(
+				"parent '{parent}' is on another file system (changed since the plan)"
+			)));
+		}
+	}
+
+	Ok(())
+}
+
+/// Best effort to make the path writable (errors are ignored, the removal reports them).
+fn clear_readonly(path: &SPath) {
+	let Ok(metadata) = fs::symlink_metadata(path.as_std_path()) else {
+		return;
+	};
+	if metadata.is_symlink() {
+		return;
+	}
+
+	let mut permissions = metadata.permissions();
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::PermissionsExt;
+		let mode = permissions.mode();
+		if mode & 0o200 != 0 {
+			return;
+		}
+		permissions.set_mode(mode | 0o200);
+	}
+
+	#[cfg(not(unix))]
+	{
+		if !permissions.readonly() {
+			return;
+		}
+		#[allow(clippy::permissions_set_readonly_false)]
+		permissions.set_readonly(false);
+	}
+
+	let _ = fs::set_permissions(path.as_std_path(), permissions);
+}
+
+/// Walks the directory (without following symlinks, nor crossing file systems) and returns the plan of its removal.
+///
+/// An entry on another file system than `dir_path` (e.g., a mount point) is refused with an `Error::DirNotSafeToRemove`.
+///
+/// Note: On non-unix systems, the walk does not descend into another file system, but it is not refused.
+fn plan_dir_removal(dir_path: &SPath) -> Result<SaferRemoveReport> {
+	let mut report = SaferRemoveReport::default();
+
+	// NOTE: A symlink to a directory is removed as a link (as `fs::remove_dir_all` does)
+	if dir_path.as_std_path().is_symlink() {
+		report.files.push(dir_path.clone());
+		return Ok(report);
+	}
+
+	let walk_err = |err: walkdir::Error| {
+		Error::DirNotSafeToRemove(PathAndCause {
+			path: dir_path.to_string(),
+			cause: Cause::Io(Box::new(std::io::Error::from(err))),
+		})
+	};
+
+	#[cfg(unix)]
+	let root_dev = {
+		use std::os::unix::fs::MetadataExt;
+		fs::symlink_metadata(dir_path.as_std_path())
+			.map_err(|err| Error::CantGetMetadata((dir_path, err).into()))?
+			.dev()
+	};
+
+	let walker = WalkDir::new(dir_path.as_std_path())
+		.follow_links(false)
+		.same_file_system(true)
+		.contents_first(true);
+	for entry in walker {
+		let entry = entry.map_err(walk_err)?;
+		let metadata = entry.metadata().map_err(walk_err)?;
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::MetadataExt;
+			if metadata.dev() != root_dev {
+				return Err(Error::DirNotSafeToRemove(PathAndCause {
+					path: dir_path.to_string(),
+					cause: Cause::Custom(format!(
+						"'{}' is on another file system (mount point)",
+						entry.path().display()
+					)),
+				}));
+			}
+		}
+
+		let file_type = entry.file_type();
+		let path = SPath::from_walkdir_entry(entry)?;
+
+		if file_type.is_dir() {
+			report.dirs.push(path);
+		} else {
+			if file_type.is_file() {
+				report.total_bytes += metadata.len();
+			}
+			report.files.push(path);
+		}
+	}
+
+	Ok(report)
+}
+
 // endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_dir;
+
+	#[test]
+	fn test_safer_remove_dir_report_dry_run_and_ceilings() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-remove-report/build");
+		ensure_dir(dir.join("sub"))?;
+		fs::write(dir.join("a.txt"), "hello")?;
+		fs::write(dir.join("sub/b.txt"), "world!")?;
+
+		// -- Exec
+		let plan = safer_remove_dir_report(&dir, SaferRemoveOptions::default().with_dry_run(true))?;
+		let too_many = safer_remove_dir_report(&dir, SaferRemoveOptions::default().with_max_files(1));
+		let too_big = safer_remove_dir_report(&dir, SaferRemoveOptions::default().with_max_bytes(10));
+		let exists_after_refused = dir.exists();
+		let report = safer_remove_dir_report(&dir, SaferRemoveOptions::default().with_max_files(2))?;
+
+		// -- Check
+		assert!(plan.dry_run);
+		assert_eq!(plan.file_count(), 2);
+		assert_eq!(plan.dir_count(), 2);
+		assert_eq!(plan.total_bytes, 11);
+		assert!(matches!(too_many, Err(Error::DirNotSafeToRemove(_))));
+		assert!(matches!(too_big, Err(Error::DirNotSafeToRemove(_))));
+		assert!(exists_after_refused);
+		assert!(!report.dry_run);
+		assert_eq!(report.file_count(), 2);
+		assert_eq!(report.dirs.last(), Some(&dir));
+		assert!(!dir.exists());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_safer_remove_globs_batch_and_prune() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-remove-globs");
+		if dir.exists() {
+			fs::remove_dir_all(&dir)?;
+		}
+		ensure_dir(dir.join("out/deep"))?;
+		ensure_dir(dir.join("src"))?;
+		fs::write(dir.join("out/deep/a.o"), "a")?;
+		fs::write(dir.join("out/b.o"), "b")?;
+		fs::write(dir.join("src/main.c"), "main")?;
+		fs::write(dir.join("src/c.o"), "c")?;
+
+		// -- Exec
+		let refused = safer_remove_globs(
+			&dir,
+			Some(&["**/*.o"]),
+			None,
+			SaferRemoveOptions::default().with_must_contain_all(&["deep"]),
+		);
+		let all_still_there = dir.join("out/b.o").exists();
+		let report = safer_remove_globs(&dir, Some(&["**/*.o"]), None, ())?;
+
+		// -- Check
+		assert!(matches!(refused, Err(Error::DirNotSafeToRemove(_))));
+		assert!(all_still_there);
+		assert_eq!(report.removed_files, 3);
+		assert_eq!(report.missing_files, 0);
+		assert_eq!(report.pruned_dirs, 2, "out/deep and out");
+		assert!(!dir.join("out").exists());
+		assert!(dir.join("src/main.c").exists());
+		assert!(dir.exists());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_safer_remove_planned_reports_each_failure() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-remove-planned/build");
+		let report = SaferRemoveReport {
+			files: vec![dir.join("sub/a.o"), dir.join("sub/b.o"), dir.join("c.o")],
+			dirs: vec![dir.join("sub"), dir.clone()],
+			..Default::default()
+		};
+		let removed = std::cell::RefCell::new(Vec::new());
+
+		// -- Exec
+		let failed = remove_planned(&report, |path, _is_dir| {
+			if path.name() == "a.o" {
+				return Err(std::io::Error::new(ErrorKind::PermissionDenied, "a.o denied"));
+			}
+			if path.name() == "b.o" {
+				return Err(std::io::Error::other("b.o busy"));
+			}
+			removed.borrow_mut().push(path.clone());
+			Ok(())
+		});
+
+		// -- Check
+		let failed: Vec<(String, String)> = failed.iter().map(|(p, e)| (p.name().to_string(), e.to_string())).collect();
+		assert_eq!(
+			failed,
+			[
+				("a.o".to_string(), "a.o denied".to_string()),
+				("b.o".to_string(), "b.o busy".to_string())
+			]
+		);
+		assert_eq!(
+			removed.into_inner(),
+			[dir.join("c.o")],
+			"the ancestors of the failed paths must not be attempted"
+		);
+
+		Ok(())
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_safer_remove_dir_symlink_not_followed() -> Result<()> {
+		// -- Setup & Fixtures
+		let base = SPath::new("target/tests-tmp/safer-remove-symlink");
+		let dir = base.join("build");
+		let outside = base.join("outside");
+		ensure_dir(&dir)?;
+		ensure_dir(&outside)?;
+		fs::write(outside.join("keep.txt"), "keep")?;
+		let link = dir.join("link-to-outside");
+		if !link.as_std_path().is_symlink() {
+			std::os::unix::fs::symlink(fs::canonicalize(&outside)?, &link)?;
+		}
+
+		// -- Exec
+		let report = safer_remove_dir_report(&dir, ())?;
+
+		// -- Check
+		assert_eq!(report.file_count(), 1, "the link is planned as a file");
+		assert_eq!(report.dir_count(), 1);
+		assert!(!dir.exists());
+		assert!(outside.join("keep.txt").exists());
+
+		Ok(())
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_safer_remove_dir_clear_readonly() -> Result<()> {
+		use std::os::unix::fs::PermissionsExt;
+
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-remove-readonly/cache");
+		let locked = dir.join("locked");
+		ensure_dir(&locked)?;
+		fs::write(locked.join("obj"), "data")?;
+		fs::set_permissions(locked.join("obj"), fs::Permissions::from_mode(0o444))?;
+		fs::set_permissions(&locked, fs::Permissions::from_mode(0o555))?;
+		// NOTE: root ignores the permission bits, so the failure is only checked when enforced
+		let is_enforced = fs::write(locked.join("probe"), "").is_err();
+
+		// -- Exec
+		let without = safer_remove_dir_report(&dir, ());
+		let with = safer_remove_dir_report(
+			&dir,
+			SaferRemoveOptions::default().with_clear_readonly(true).with_max_retries(1),
+		);
+
+		// -- Check
+		if is_enforced {
+			let Err(Error::DirNotFullyRemoved { failed, .. }) = without else {
+				return Err("should be DirNotFullyRemoved".into());
+			};
+			assert_eq!(failed.len(), 1);
+			assert!(failed[0].path.ends_with("locked/obj"));
+			with?;
+		}
+		assert!(!dir.exists());
+
+		Ok(())
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_safer_remove_clear_readonly_never_touches_parent() -> Result<()> {
+		use std::os::unix::fs::PermissionsExt;
+
+		// -- Setup & Fixtures
+		let base = SPath::new("target/tests-tmp/safer-remove-readonly-parent");
+		let parent = base.join("ro-parent");
+		let dir = parent.join("cache");
+		ensure_dir(dir.join("sub"))?;
+		fs::write(parent.join("file.txt"), "data")?;
+		fs::set_permissions(&parent, fs::Permissions::from_mode(0o555))?;
+		let options = SaferRemoveOptions::default().with_clear_readonly(true);
+
+		// -- Exec
+		// NOTE: The removals fail when the permission bits are enforced (not for root), only the parent matters here
+		let _ = safer_remove_file(&parent.join("file.txt"), options.clone());
+		let _ = safer_remove_dir(&dir, options);
+		let parent_mode = fs::metadata(&parent)?.permissions().mode() & 0o777;
+		fs::set_permissions(&parent, fs::Permissions::from_mode(0o755))?;
+
+		// -- Check
+		assert_eq!(parent_mode, 0o555, "the parent of the targets must be left as is");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
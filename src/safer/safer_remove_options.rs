@@ -2,7 +2,24 @@
 pub struct SaferRemoveOptions<'a> {
 	pub must_contain_any: Option<&'a [&'a str]>,
 	pub must_contain_all: Option<&'a [&'a str]>,
+	/// Globs the path (as given or resolved) must match at least one of.
+	pub must_match_any_glob: Option<&'a [&'a str]>,
 	pub restrict_to_current_dir: bool,
+	/// Roots the resolved path must be strictly below (component-aware) at least one of.
+	pub allowed_roots: Option<&'a [&'a str]>,
+	/// When true, nothing is removed (`safer_remove_dir_report` returns the plan).
+	pub dry_run: bool,
+	/// Refuse the directory removal if it has more files than this (checked before anything is removed).
+	pub max_files: Option<usize>,
+	/// Refuse the directory removal if its files total more bytes than this (checked before anything is removed).
+	pub max_bytes: Option<u64>,
+	/// When true, the read-only permission bits are cleared before removal (e.g., git objects, Go module caches).
+	///
+	/// Only the paths strictly inside the removed directory (or the `dir` of `safer_remove_globs`) are changed,
+	/// so a read-only parent of the target (or a read-only target directory itself) still fails the removal.
+	pub clear_readonly: bool,
+	/// Number of retries (with exponential backoff from 10ms) of a failed removal (e.g., transient `EBUSY`).
+	pub max_retries: u32,
 }
 
 // region:    --- Default
@@ -12,7 +29,14 @@ impl Default for SaferRemoveOptions<'_> {
 		Self {
 			must_contain_any: None,
 			must_contain_all: None,
+			must_match_any_glob: None,
 			restrict_to_current_dir: true,
+			allowed_roots: None,
+			dry_run: false,
+			max_files: None,
+			max_bytes: None,
+			clear_readonly: false,
+			max_retries: 0,
 		}
 	}
 }
@@ -51,10 +75,45 @@ impl<'a> SaferRemoveOptions<'a> {
 		self
 	}
 
+	pub fn with_must_match_any_glob(mut self, globs: &'a [&'a str]) -> Self {
+		self.must_match_any_glob = Some(globs);
+		self
+	}
+
 	pub fn with_restrict_to_current_dir(mut self, val: bool) -> Self {
 		self.restrict_to_current_dir = val;
 		self
 	}
+
+	pub fn with_allowed_roots(mut self, roots: &'a [&'a str]) -> Self {
+		self.allowed_roots = Some(roots);
+		self
+	}
+
+	pub fn with_dry_run(mut self, val: bool) -> Self {
+		self.dry_run = val;
+		self
+	}
+
+	pub fn with_max_files(mut self, max_files: usize) -> Self {
+		self.max_files = Some(max_files);
+		self
+	}
+
+	pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+		self.max_bytes = Some(max_bytes);
+		self
+	}
+
+	pub fn with_clear_readonly(mut self, val: bool) -> Self {
+		self.clear_readonly = val;
+		self
+	}
+
+	pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
 }
 
 // endregion: --- Fluent API
@@ -0,0 +1,51 @@
+use crate::SPath;
+
+/// The plan (dry-run) or audit report (real-run) of a `safer_remove_dir_report(...)`.
+///
+/// Entries are in removal order (directory contents before the directory itself).
+#[derive(Debug, Clone, Default)]
+pub struct SaferRemoveReport {
+	/// True when nothing was removed (see `SaferRemoveOptions::with_dry_run`).
+	pub dry_run: bool,
+
+	/// The files (and symlinks, which are never followed) planned or removed.
+	pub files: Vec<SPath>,
+
+	/// The directories planned or removed.
+	pub dirs: Vec<SPath>,
+
+	/// The total size in bytes of the files (via `SMeta`, symlinks count as 0).
+	pub total_bytes: u64,
+}
+
+/// Getters
+impl SaferRemoveReport {
+	pub fn file_count(&self) -> usize {
+		self.files.len()
+	}
+
+	pub fn dir_count(&self) -> usize {
+		self.dirs.len()
+	}
+
+	/// Returns true if there is nothing planned or removed (e.g., the directory did not exist).
+	pub fn is_empty(&self) -> bool {
+		self.files.is_empty() && self.dirs.is_empty()
+	}
+}
+
+/// The counts per outcome of a `safer_remove_globs(...)`.
+#[derive(Debug, Clone, Default)]
+pub struct SaferRemoveGlobsReport {
+	/// True when nothing was removed (see `SaferRemoveOptions::with_dry_run`).
+	pub dry_run: bool,
+
+	/// Number of files removed (or that would be removed, with `dry_run`).
+	pub removed_files: usize,
+
+	/// Number of listed files which were already gone when removing.
+	pub missing_files: usize,
+
+	/// Number of directories removed because they were left empty (always 0 with `dry_run`).
+	pub pruned_dirs: usize,
+}
@@ -9,6 +9,9 @@ use crate::{Error, Result};
 /// - If `restrict_to_current_dir` is true, the directory path must be below the current directory
 /// - If `must_contain_any` is set, the path must contain at least one of the specified patterns
 /// - If `must_contain_all` is set, the path must contain all of the specified patterns
+/// - If `must_match_any_glob` is set, the path must match at least one of the specified globs
+/// - If `allowed_roots` is set, the resolved path must be below at least one of the roots
+/// - The filesystem root, the home directory (and its parents), and mount points are always refused
 ///
 /// Returns Ok(true) if the directory was trashed, Ok(false) if it didn't exist.
 /// Returns an error if safety checks fail or trashing fails.
@@ -20,12 +23,7 @@ pub fn safer_trash_dir<'a>(dir_path: &SPath, options: impl Into<SaferTrashOption
 		return Ok(false);
 	}
 
-	let causes = support::check_path_safety_causes(
-		dir_path,
-		options.restrict_to_current_dir,
-		options.must_contain_any,
-		options.must_contain_all,
-	)?;
+	let causes = support::check_path_safety_causes(dir_path, &(&options).into())?;
 
 	if !causes.is_empty() {
 		return Err(Error::DirNotSafeToTrash(PathAndCause {
@@ -51,6 +49,9 @@ pub fn safer_trash_dir<'a>(dir_path: &SPath, options: impl Into<SaferTrashOption
 /// - If `restrict_to_current_dir` is true, the file path must be below the current directory
 /// - If `must_contain_any` is set, the path must contain at least one of the specified patterns
 /// - If `must_contain_all` is set, the path must contain all of the specified patterns
+/// - If `must_match_any_glob` is set, the path must match at least one of the specified globs
+/// - If `allowed_roots` is set, the resolved path must be below at least one of the roots
+/// - The filesystem root, the home directory (and its parents), and mount points are always refused
 ///
 /// Returns Ok(true) if the file was trashed, Ok(false) if it didn't exist.
 /// Returns an error if safety checks fail or trashing fails.
@@ -62,12 +63,7 @@ pub fn safer_trash_file<'a>(file_path: &SPath, options: impl Into<SaferTrashOpti
 		return Ok(false);
 	}
 
-	let causes = support::check_path_safety_causes(
-		file_path,
-		options.restrict_to_current_dir,
-		options.must_contain_any,
-		options.must_contain_all,
-	)?;
+	let causes = support::check_path_safety_causes(file_path, &(&options).into())?;
 
 	if !causes.is_empty() {
 		return Err(Error::FileNotSafeToTrash(PathAndCause {
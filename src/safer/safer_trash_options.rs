@@ -2,7 +2,11 @@
 pub struct SaferTrashOptions<'a> {
 	pub must_contain_any: Option<&'a [&'a str]>,
 	pub must_contain_all: Option<&'a [&'a str]>,
+	/// Globs the path (as given or resolved) must match at least one of.
+	pub must_match_any_glob: Option<&'a [&'a str]>,
 	pub restrict_to_current_dir: bool,
+	/// Roots the resolved path must be strictly below (component-aware) at least one of.
+	pub allowed_roots: Option<&'a [&'a str]>,
 }
 
 // region:    --- Default
@@ -12,7 +16,9 @@ impl Default for SaferTrashOptions<'_> {
 		Self {
 			must_contain_any: None,
 			must_contain_all: None,
+			must_match_any_glob: None,
 			restrict_to_current_dir: true,
+			allowed_roots: None,
 		}
 	}
 }
@@ -51,10 +57,20 @@ impl<'a> SaferTrashOptions<'a> {
 		self
 	}
 
+	pub fn with_must_match_any_glob(mut self, globs: &'a [&'a str]) -> Self {
+		self.must_match_any_glob = Some(globs);
+		self
+	}
+
 	pub fn with_restrict_to_current_dir(mut self, val: bool) -> Self {
 		self.restrict_to_current_dir = val;
 		self
 	}
+
+	pub fn with_allowed_roots(mut self, roots: &'a [&'a str]) -> Self {
+		self.allowed_roots = Some(roots);
+		self
+	}
 }
 
 // endregion: --- Fluent API
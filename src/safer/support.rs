@@ -1,61 +1,331 @@
 use crate::error::{Cause, PathAndCause};
-use crate::{Error, Result, SPath};
+use crate::safer::{SaferRemoveOptions, SaferTrashOptions};
+use crate::{Error, Result, SPath, get_glob_set, home_dir};
+use globset::GlobSet;
+use std::path::Path;
 
-/// Performs safety checks before deletion or trashing based on the provided options.
+/// The safety rules shared by `SaferRemoveOptions` and `SaferTrashOptions`.
+pub(crate) struct SafetyRules<'a> {
+	pub restrict_to_current_dir: bool,
+	pub allowed_roots: Option<&'a [&'a str]>,
+	pub must_contain_any: Option<&'a [&'a str]>,
+	pub must_contain_all: Option<&'a [&'a str]>,
+	pub must_match_any_glob: Option<&'a [&'a str]>,
+}
+
+impl<'a> From<&SaferRemoveOptions<'a>> for SafetyRules<'a> {
+	fn from(options: &SaferRemoveOptions<'a>) -> Self {
+		Self {
+			restrict_to_current_dir: options.restrict_to_current_dir,
+			allowed_roots: options.allowed_roots,
+			must_contain_any: options.must_contain_any,
+			must_contain_all: options.must_contain_all,
+			must_match_any_glob: options.must_match_any_glob,
+		}
+	}
+}
+
+impl<'a> From<&SaferTrashOptions<'a>> for SafetyRules<'a> {
+	fn from(options: &SaferTrashOptions<'a>) -> Self {
+		Self {
+			restrict_to_current_dir: options.restrict_to_current_dir,
+			allowed_roots: options.allowed_roots,
+			must_contain_any: options.must_contain_any,
+			must_contain_all: options.must_contain_all,
+			must_match_any_glob: options.must_match_any_glob,
+		}
+	}
+}
+
+/// Performs safety checks before deletion or trashing based on the provided rules.
 /// Returns a list of error causes if safety checks fail.
-pub(crate) fn check_path_safety_causes(
-	path: &SPath,
-	restrict_to_current_dir: bool,
-	must_contain_any: Option<&[&str]>,
-	must_contain_all: Option<&[&str]>,
-) -> Result<Vec<String>> {
-	let mut error_causes = Vec::new();
-
-	// Resolve the path to absolute
-	let resolved = path.canonicalize()?;
-	let resolved_str = resolved.as_str();
-	let path_str = path.as_str();
-
-	// Check that the path is below current directory (if enabled)
-	if restrict_to_current_dir {
-		let current_dir = std::env::current_dir().map_err(|e| {
-			Error::CantGetMetadata(PathAndCause {
-				path: path.to_string(),
-				cause: Cause::Io(Box::new(e)),
-			})
-		})?;
-		let current_dir_path = SPath::from_std_path_buf(current_dir)?;
-		let current_resolved = current_dir_path.canonicalize()?;
-		let current_str = current_resolved.as_str();
-
-		if !resolved_str.starts_with(current_str) {
-			error_causes.push(format!("is not below current directory '{current_resolved}'"));
-		}
-	}
-
-	// Check must_contain_any
-	if let Some(patterns) = must_contain_any {
-		if patterns.is_empty() {
-			error_causes.push("must_contain_any cannot be an empty list (use None to disable)".to_string());
+///
+/// Note: The built-in deny-list (filesystem root, home directory and its parents, mount points) is always checked.
+///       To check many paths against the same rules, use `SafetyRules::compile` once and `CompiledSafetyRules::check_causes`.
+pub(crate) fn check_path_safety_causes(path: &SPath, rules: &SafetyRules) -> Result<Vec<String>> {
+	rules.compile()?.check_causes(path)
+}
+
+/// The `SafetyRules` with their inputs resolved once (home, current directory, allowed roots, compiled globs),
+/// to check many paths (e.g., `safer_remove_globs`).
+pub(crate) struct CompiledSafetyRules<'a> {
+	rules: &'a SafetyRules<'a>,
+	/// The canonicalized home directory (if any)
+	home: Option<SPath>,
+	/// The canonicalized current directory (only when `restrict_to_current_dir`)
+	current_dir: Option<SPath>,
+	/// The canonicalized allowed roots (a root which does not exist cannot contain any path)
+	allowed_roots: Vec<SPath>,
+	/// The compiled `must_match_any_glob` (only when not empty)
+	glob_set: Option<GlobSet>,
+}
+
+impl<'a> SafetyRules<'a> {
+	pub(crate) fn compile(&'a self) -> Result<CompiledSafetyRules<'a>> {
+		let home = home_dir().and_then(|home| home.canonicalize()).ok();
+
+		let current_dir = if self.restrict_to_current_dir {
+			let current_dir = std::env::current_dir().map_err(|e| {
+				Error::CantGetMetadata(PathAndCause {
+					path: ".".to_string(),
+					cause: Cause::Io(Box::new(e)),
+				})
+			})?;
+			Some(SPath::from_std_path_buf(current_dir)?.canonicalize()?)
 		} else {
-			let has_any = patterns.iter().any(|s| path_str.contains(s));
-			if !has_any {
-				error_causes.push(format!("does not contain any of the required patterns: {patterns:?}"));
+			None
+		};
+
+		let allowed_roots = self
+			.allowed_roots
+			.unwrap_or_default()
+			.iter()
+			.filter_map(|root| SPath::new(*root).canonicalize().ok())
+			.collect();
+
+		let glob_set = match self.must_match_any_glob {
+			Some(globs) if !globs.is_empty() => Some(get_glob_set(globs)?),
+			_ => None,
+		};
+
+		Ok(CompiledSafetyRules {
+			rules: self,
+			home,
+			current_dir,
+			allowed_roots,
+			glob_set,
+		})
+	}
+}
+
+impl CompiledSafetyRules<'_> {
+	/// Returns the list of error causes if the safety checks fail for this path (empty when safe).
+	pub(crate) fn check_causes(&self, path: &SPath) -> Result<Vec<String>> {
+		let rules = self.rules;
+		let mut error_causes = Vec::new();
+
+		// Resolve the path to absolute
+		let resolved = path.canonicalize()?;
+		let path_str = path.as_str();
+
+		// Check the built-in deny-list
+		if let Some(cause) = denied_cause(&resolved, self.home.as_ref()) {
+			error_causes.push(cause);
+		}
+
+		// Check that the path is below current directory (if enabled)
+		// NOTE: Component-aware (`/work/app` does not contain `/work/app-other`)
+		if let Some(current_dir) = &self.current_dir
+			&& !resolved.starts_with(current_dir)
+		{
+			error_causes.push(format!("is not below current directory '{current_dir}'"));
+		}
+
+		// Check allowed_roots
+		if let Some(roots) = rules.allowed_roots {
+			if roots.is_empty() {
+				error_causes.push("allowed_roots cannot be an empty list (use None to disable)".to_string());
+			} else {
+				let is_allowed = self
+					.allowed_roots
+					.iter()
+					.any(|root| resolved.starts_with(root) && &resolved != root);
+				if !is_allowed {
+					error_causes.push(format!("is not below any of the allowed roots: {roots:?}"));
+				}
 			}
 		}
-	}
 
-	// Check must_contain_all
-	if let Some(patterns) = must_contain_all {
-		if patterns.is_empty() {
-			error_causes.push("must_contain_all cannot be an empty list (use None to disable)".to_string());
-		} else {
-			let missing: Vec<_> = patterns.iter().filter(|s| !path_str.contains(*s)).collect();
-			if !missing.is_empty() {
-				error_causes.push(format!("does not contain all required patterns, missing: {missing:?}"));
+		// Check must_contain_any
+		if let Some(patterns) = rules.must_contain_any {
+			if patterns.is_empty() {
+				error_causes.push("must_contain_any cannot be an empty list (use None to disable)".to_string());
+			} else {
+				let has_any = patterns.iter().any(|s| path_str.contains(s));
+				if !has_any {
+					error_causes.push(format!("does not contain any of the required patterns: {patterns:?}"));
+				}
 			}
 		}
+
+		// Check must_contain_all
+		if let Some(patterns) = rules.must_contain_all {
+			if patterns.is_empty() {
+				error_causes.push("must_contain_all cannot be an empty list (use None to disable)".to_string());
+			} else {
+				let missing: Vec<_> = patterns.iter().filter(|s| !path_str.contains(*s)).collect();
+				if !missing.is_empty() {
+					error_causes.push(format!("does not contain all required patterns, missing: {missing:?}"));
+				}
+			}
+		}
+
+		// Check must_match_any_glob (on the path as given or the resolved path)
+		if let Some(globs) = rules.must_match_any_glob {
+			match &self.glob_set {
+				None => {
+					error_causes.push("must_match_any_glob cannot be an empty list (use None to disable)".to_string());
+				}
+				Some(glob_set) => {
+					if !glob_set.is_match(path_str) && !glob_set.is_match(resolved.as_str()) {
+						error_causes.push(format!("does not match any of the required globs: {globs:?}"));
+					}
+				}
+			}
+		}
+
+		Ok(error_causes)
 	}
+}
+
+// region:    --- Deny List
+
+/// Returns the cause if the resolved path is in the built-in deny-list
+/// (filesystem root, home directory or one of its parents, mount points).
+///
+/// `home` is the canonicalized home directory (if any).
+fn denied_cause(resolved: &SPath, home: Option<&SPath>) -> Option<String> {
+	let path = resolved.std_path();
 
-	Ok(error_causes)
+	if path.parent().is_none() {
+		return Some("is a filesystem root (always denied)".to_string());
+	}
+
+	// NOTE: Component-aware, so `/home/user` denies `/home` but not `/home/user-other`
+	if home.is_some_and(|home| home.starts_with(resolved)) {
+		return Some("is the home directory or one of its parents (always denied)".to_string());
+	}
+
+	if is_mount_point(path) {
+		return Some("is a mount point (always denied)".to_string());
+	}
+
+	None
+}
+
+/// A directory is a mount point when it is on a different device than its parent.
+#[cfg(unix)]
+fn is_mount_point(path: &Path) -> bool {
+	use std::os::unix::fs::MetadataExt;
+
+	let Some(parent) = path.parent() else {
+		return true;
+	};
+
+	// NOTE: Only a directory can be a mount point (so a file does not stat its parent)
+	let Ok(meta) = std::fs::symlink_metadata(path) else {
+		return false;
+	};
+	if !meta.is_dir() {
+		return false;
+	}
+
+	std::fs::metadata(parent).is_ok_and(|parent_meta| meta.dev() != parent_meta.dev())
+}
+
+/// On non-unix systems, only the roots (e.g., `C:\`) are considered mount points.
+#[cfg(not(unix))]
+fn is_mount_point(path: &Path) -> bool {
+	path.parent().is_none()
+}
+
+// endregion: --- Deny List
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ensure_dir;
+
+	fn rules<'a>() -> SafetyRules<'a> {
+		SafetyRules {
+			restrict_to_current_dir: false,
+			allowed_roots: None,
+			must_contain_any: None,
+			must_contain_all: None,
+			must_match_any_glob: None,
+		}
+	}
+
+	#[test]
+	fn test_safer_support_allowed_roots_component_aware() -> Result<()> {
+		// -- Setup & Fixtures
+		let root = SPath::new("target/tests-tmp/safer-roots/app");
+		let other = SPath::new("target/tests-tmp/safer-roots/app-other");
+		let inside = root.join("cache");
+		ensure_dir(&inside)?;
+		ensure_dir(&other)?;
+		let roots = [root.as_str()];
+		let rules = SafetyRules {
+			allowed_roots: Some(&roots),
+			..rules()
+		};
+
+		// -- Exec
+		let root_causes = check_path_safety_causes(&root, &rules)?;
+		let other_causes = check_path_safety_causes(&other, &rules)?;
+		let inside_causes = check_path_safety_causes(&inside, &rules)?;
+
+		// -- Check
+		assert_eq!(root_causes.len(), 1, "the root itself is not below the root");
+		assert_eq!(other_causes.len(), 1, "'app-other' must not be admitted by 'app'");
+		assert!(inside_causes.is_empty(), "{inside_causes:?}");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_safer_support_deny_list_and_globs() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-globs/cache");
+		ensure_dir(&dir)?;
+		let globs = ["**/safer-globs/*"];
+		let rules = SafetyRules {
+			must_match_any_glob: Some(&globs),
+			..rules()
+		};
+
+		// -- Exec
+		let dir_causes = check_path_safety_causes(&dir, &rules)?;
+		let root_causes = check_path_safety_causes(&SPath::new("/"), &rules)?;
+
+		// -- Check
+		assert!(dir_causes.is_empty(), "{dir_causes:?}");
+		assert!(
+			root_causes.iter().any(|c| c.contains("filesystem root")),
+			"{root_causes:?}"
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_safer_support_deny_home_and_parents() -> Result<()> {
+		// -- Setup & Fixtures
+		let probe = SPath::new("target/tests-tmp/safer-home-probe");
+		let home = probe.join("h/user");
+		let other = probe.join("h/user-other");
+		ensure_dir(home.join("projects"))?;
+		ensure_dir(&other)?;
+		let home = home.canonicalize()?;
+
+		// -- Exec
+		let parent_cause = denied_cause(&probe.join("h").canonicalize()?, Some(&home));
+		let home_cause = denied_cause(&home, Some(&home));
+		let other_cause = denied_cause(&other.canonicalize()?, Some(&home));
+		let inside_cause = denied_cause(&home.join("projects"), Some(&home));
+
+		// -- Check
+		assert!(parent_cause.is_some(), "a parent of home must be denied");
+		assert!(home_cause.is_some(), "home must be denied");
+		assert!(other_cause.is_none(), "{other_cause:?}");
+		assert!(inside_cause.is_none(), "{inside_cause:?}");
+
+		Ok(())
+	}
 }
+
+// endregion: --- Tests
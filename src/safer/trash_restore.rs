@@ -0,0 +1,364 @@
+//! Listing and restoring of the items sent to the trash (freedesktop trash spec, Linux only).
+
+use crate::error::{Cause, PathAndCause};
+use crate::safer::support;
+use crate::{Error, Result, SPath, SaferTrashOptions, ensure_dir};
+use std::fs;
+use trash::TrashItem;
+
+// region:    --- Types
+
+/// An item in the trash, as returned by `list_trashed(...)`.
+#[derive(Debug, Clone)]
+pub struct STrashedItem {
+	/// The path of the item before it was trashed.
+	pub original_path: SPath,
+
+	/// Deletion time since the Unix epoch in microseconds.
+	pub deleted_epoch_us: i64,
+
+	item: TrashItem,
+}
+
+/// The filter of `list_trashed(...)`.
+///
+/// The original path prefix is required, so that only the items trashed from a known location
+/// (e.g., the items the tool trashed with `safer_trash_...`) are listed and restored.
+#[derive(Debug, Clone)]
+pub struct TrashedFilter<'a> {
+	/// The original path must be this path or below it (component-aware).
+	pub original_prefix: &'a str,
+
+	/// If set, only the items deleted at or after this time (epoch microseconds).
+	pub deleted_after_epoch_us: Option<i64>,
+}
+
+/// What `restore_trashed(...)` does when something already exists at the original path.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RestoreConflictPolicy {
+	/// Returns an `Error::CantRestoreTrashed` (default).
+	#[default]
+	Fail,
+	/// Leaves the item in the trash and returns `Ok(false)`.
+	Skip,
+	/// Sends the existing path to the trash and restores the item.
+	///
+	/// The existing path must pass the built-in deny-list (see `safer_trash_...`), and is moved aside
+	/// (renamed in its directory) during the restore, so it is put back if the restore fails.
+	///
+	/// The existing path is trashed under its original name, so the overwrite can be undone
+	/// with `list_trashed(...)` and `restore_trashed(...)`.
+	Overwrite,
+}
+
+// endregion: --- Types
+
+// region:    --- Froms & Fluent API
+
+impl<'a> TrashedFilter<'a> {
+	pub fn new(original_prefix: &'a str) -> Self {
+		Self {
+			original_prefix,
+			deleted_after_epoch_us: None,
+		}
+	}
+
+	pub fn with_deleted_after_epoch_us(mut self, epoch_us: i64) -> Self {
+		self.deleted_after_epoch_us = Some(epoch_us);
+		self
+	}
+}
+
+impl<'a> From<&'a str> for TrashedFilter<'a> {
+	fn from(original_prefix: &'a str) -> Self {
+		Self::new(original_prefix)
+	}
+}
+
+impl<'a> From<&'a SPath> for TrashedFilter<'a> {
+	fn from(original_prefix: &'a SPath) -> Self {
+		Self::new(original_prefix.as_str())
+	}
+}
+
+// endregion: --- Froms & Fluent API
+
+// region:    --- Public Functions
+
+/// Lists the trashed items matching the filter, most recently deleted first.
+///
+/// Note: The original prefix is resolved to absolute (the trash records absolute original paths).
+pub fn list_trashed<'a>(filter: impl Into<TrashedFilter<'a>>) -> Result<Vec<STrashedItem>> {
+	let filter = filter.into();
+	let prefix = absolute_prefix(filter.original_prefix)?;
+
+	let items = trash::os_limited::list().map_err(|err| {
+		Error::CantListTrashed(PathAndCause {
+			path: prefix.to_string(),
+			cause: Cause::Custom(err.to_string()),
+		})
+	})?;
+
+	let mut trashed: Vec<STrashedItem> = items
+		.into_iter()
+		.filter_map(|item| {
+			let original_path = SPath::from_std_path_buf(item.original_path()).ok()?;
+			let deleted_epoch_us = item.time_deleted.saturating_mul(1_000_000);
+			Some(STrashedItem {
+				original_path,
+				deleted_epoch_us,
+				item,
+			})
+		})
+		.filter(|t| t.original_path.starts_with(&prefix))
+		.filter(|t| filter.deleted_after_epoch_us.is_none_or(|after| t.deleted_epoch_us >= after))
+		.collect();
+
+	trashed.sort_by_key(|t| std::cmp::Reverse(t.deleted_epoch_us));
+
+	Ok(trashed)
+}
+
+/// Restores a trashed item (from `list_trashed(...)`) to its original path.
+///
+/// The missing parent directories are created.
+///
+/// Returns Ok(true) if restored, Ok(false) if skipped (`RestoreConflictPolicy::Skip`).
+pub fn restore_trashed(item: STrashedItem, conflict_policy: RestoreConflictPolicy) -> Result<bool> {
+	let original_path = item.original_path;
+
+	// -- Handle the conflict (symlink_metadata, so a dangling symlink is a conflict as well)
+	if original_path.as_std_path().symlink_metadata().is_ok() {
+		match conflict_policy {
+			RestoreConflictPolicy::Fail => {
+				return Err(Error::CantRestoreTrashed(PathAndCause {
+					path: original_path.to_string(),
+					cause: Cause::Custom("Original path already exists".to_string()),
+				}));
+			}
+			RestoreConflictPolicy::Skip => return Ok(false),
+			RestoreConflictPolicy::Overwrite => return restore_overwrite(item.item, &original_path),
+		}
+	}
+
+	if let Some(parent) = original_path.parent() {
+		ensure_dir(parent)?;
+	}
+
+	restore_item(item.item, &original_path)?;
+
+	Ok(true)
+}
+
+// endregion: --- Public Functions
+
+// region:    --- Support
+
+/// Max attempts to find a free name to move the existing path aside.
+const ASIDE_NAME_MAX_ATTEMPTS: u32 = 100;
+
+/// Restores the item over the existing `original_path` (`RestoreConflictPolicy::Overwrite`).
+///
+/// The existing path is moved aside, put back if the restore fails, and trashed (under its original name)
+/// once the item is restored.
+fn restore_overwrite(item: TrashItem, original_path: &SPath) -> Result<bool> {
+	// -- Check the existing path against the built-in deny-list
+	// NOTE: A symlink is moved aside as a link (its target is never touched), so only checked when not one
+	if !original_path.as_std_path().is_symlink() {
+		let options = SaferTrashOptions::default().with_restrict_to_current_dir(false);
+		let causes = support::check_path_safety_causes(original_path, &(&options).into())?;
+		if !causes.is_empty() {
+			return Err(Error::CantRestoreTrashed(PathAndCause {
+				path: original_path.to_string(),
+				cause: Cause::Custom(format!("Existing path not safe to overwrite: {}", causes.join("; "))),
+			}));
+		}
+	}
+
+	// -- Move the existing path aside
+	let aside_path = aside_path(original_path)?;
+	fs::rename(original_path, &aside_path).map_err(|err| Error::CantRestoreTrashed((original_path, err).into()))?;
+
+	// -- Restore (or put the existing path back)
+	if let Err(err) = restore_item(item, original_path) {
+		if original_path.as_std_path().symlink_metadata().is_err() {
+			let _ = fs::rename(&aside_path, original_path);
+		}
+		return Err(err);
+	}
+
+	trash_under_original_name(&aside_path, original_path)?;
+
+	Ok(true)
+}
+
+/// Trashes the existing path moved to `aside_path` under its `original_path` name,
+/// by swapping it with the restored item for the time of the trashing.
+///
+/// On error, the existing path is left at `aside_path` (which the error tells).
+fn trash_under_original_name(aside_path: &SPath, original_path: &SPath) -> Result<()> {
+	let trash_err = |cause: String| {
+		Error::CantTrash(PathAndCause {
+			path: aside_path.to_string(),
+			cause: Cause::Custom(cause),
+		})
+	};
+
+	// -- Move the restored item aside, and the existing path back to its original name
+	let restored_aside = self::aside_path(original_path)?;
+	fs::rename(original_path, &restored_aside).map_err(|err| trash_err(err.to_string()))?;
+	if let Err(err) = fs::rename(aside_path, original_path) {
+		let _ = fs::rename(&restored_aside, original_path);
+		return Err(trash_err(err.to_string()));
+	}
+
+	// -- Trash the existing path (moved back aside if it fails)
+	let trash_res = trash::delete(original_path.as_std_path()).map_err(|err| trash_err(err.to_string()));
+	if trash_res.is_err() {
+		let _ = fs::rename(original_path, aside_path);
+	}
+
+	// -- Put the restored item back
+	fs::rename(&restored_aside, original_path).map_err(|err| {
+		Error::CantRestoreTrashed(PathAndCause {
+			path: restored_aside.to_string(),
+			cause: Cause::Custom(format!(
+				"Restored item left aside (cannot rename it to '{original_path}'): {err}"
+			)),
+		})
+	})?;
+
+	trash_res
+}
+
+fn restore_item(item: TrashItem, original_path: &SPath) -> Result<()> {
+	trash::os_limited::restore_all([item]).map_err(|err| {
+		Error::CantRestoreTrashed(PathAndCause {
+			path: original_path.to_string(),
+			cause: Cause::Custom(err.to_string()),
+		})
+	})
+}
+
+/// Returns a free sibling path to move `original_path` aside (e.g., `.note.txt.sfs-restore-1f2a-0`).
+fn aside_path(original_path: &SPath) -> Result<SPath> {
+	let pid = std::process::id();
+	for count in 0..ASIDE_NAME_MAX_ATTEMPTS {
+		let path = original_path.new_sibling(format!(".{}.sfs-restore-{pid:x}-{count}", original_path.name()));
+		if path.as_std_path().symlink_metadata().is_err() {
+			return Ok(path);
+		}
+	}
+
+	Err(Error::CantRestoreTrashed(PathAndCause {
+		path: original_path.to_string(),
+		cause: Cause::Custom(format!(
+			"No free name to move the existing path aside after {ASIDE_NAME_MAX_ATTEMPTS} attempts"
+		)),
+	}))
+}
+
+fn absolute_prefix(original_prefix: &str) -> Result<SPath> {
+	let prefix = SPath::new(original_prefix);
+	// NOTE: Canonicalize when possible (the prefix may have been trashed itself)
+	if let Ok(prefix) = prefix.canonicalize() {
+		return Ok(prefix);
+	}
+	let abs = std::path::absolute(prefix.as_std_path())
+		.map_err(|err| Error::CannotCanonicalize((prefix.std_path(), err).into()))?;
+	Ok(SPath::from_std_path_buf(abs)?.into_collapsed())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::safer_trash_file;
+
+	/// Purges the items trashed from `dir` (these tests use the user trash, so they must not leave items in it).
+	fn purge_trashed(dir: &SPath) -> Result<()> {
+		let items: Vec<TrashItem> = list_trashed(dir)?.into_iter().map(|t| t.item).collect();
+		trash::os_limited::purge_all(items)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_safer_trash_list_and_restore() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-trash-restore");
+		ensure_dir(&dir)?;
+		let file = dir.join("note.txt");
+		fs::write(&file, "first")?;
+		if safer_trash_file(&file, SaferTrashOptions::default()).is_err() {
+			// NOTE: No usable trash in this environment (e.g., no home trash dir permission)
+			return Ok(());
+		}
+
+		// -- Exec
+		let trashed = list_trashed(&dir)?;
+		let item = trashed.first().ok_or("should have one trashed item")?.clone();
+		fs::write(&file, "second")?;
+		let skipped = restore_trashed(item.clone(), RestoreConflictPolicy::Skip)?;
+		let failed = restore_trashed(item.clone(), RestoreConflictPolicy::Fail);
+		fs::remove_file(&file)?;
+		let restored = restore_trashed(item, RestoreConflictPolicy::Fail)?;
+		let remaining = list_trashed(&dir)?.len();
+		purge_trashed(&dir)?;
+
+		// -- Check
+		assert!(trashed[0].original_path.as_str().ends_with("safer-trash-restore/note.txt"));
+		assert!(!skipped);
+		assert!(matches!(failed, Err(Error::CantRestoreTrashed(_))));
+		assert!(restored);
+		assert_eq!(fs::read_to_string(&file)?, "first");
+		assert_eq!(remaining, trashed.len() - 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_safer_trash_restore_overwrite() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/safer-trash-restore-overwrite");
+		ensure_dir(&dir)?;
+		let file = dir.join("note.txt");
+		fs::write(&file, "trashed")?;
+		if safer_trash_file(&file, SaferTrashOptions::default()).is_err() {
+			// NOTE: No usable trash in this environment (e.g., no home trash dir permission)
+			return Ok(());
+		}
+		fs::write(&file, "existing")?;
+
+		// -- Exec
+		let item = list_trashed(&dir)?.into_iter().next().ok_or("should have one trashed item")?;
+		let restored = restore_trashed(item, RestoreConflictPolicy::Overwrite)?;
+		let content_after_overwrite = fs::read_to_string(&file)?;
+		let names: Vec<String> = fs::read_dir(&dir)?
+			.filter_map(|entry| entry.ok())
+			.map(|entry| entry.file_name().to_string_lossy().to_string())
+			.collect();
+		// Undo the overwrite (the existing path was trashed under its original name)
+		let existing = list_trashed(&dir)?.into_iter().next().ok_or("existing should be trashed")?;
+		let existing_path = existing.original_path.clone();
+		restore_trashed(existing, RestoreConflictPolicy::Overwrite)?;
+		let content_after_undo = fs::read_to_string(&file)?;
+		purge_trashed(&dir)?;
+
+		// -- Check
+		assert!(restored);
+		assert_eq!(content_after_overwrite, "trashed");
+		assert_eq!(names, ["note.txt"], "the existing path must not be left aside");
+		assert_eq!(existing_path.name(), "note.txt");
+		assert_eq!(content_after_undo, "existing");
+		assert!(list_trashed(&dir)?.is_empty());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
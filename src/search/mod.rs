@@ -0,0 +1,15 @@
+// region:    --- Modules
+
+mod replace_in_files;
+mod replace_options;
+mod search_files;
+mod search_options;
+mod search_pattern;
+
+pub use replace_in_files::*;
+pub use replace_options::*;
+pub use search_files::*;
+pub use search_options::*;
+pub use search_pattern::*;
+
+// endregion: --- Modules
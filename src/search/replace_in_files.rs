@@ -0,0 +1,335 @@
+use super::search_files::line_ranges;
+use super::search_pattern::PatternMatcher;
+use crate::{Error, ReplaceOptions, Result, SPath, SearchPattern, write_atomic};
+use std::fs;
+
+/// Number of unchanged lines shown around each change in the dry-run diff.
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// The outcome of a replace for one file (only files with at least one replacement are reported).
+#[derive(Debug, Clone)]
+pub struct ReplaceReport {
+	pub path: SPath,
+
+	/// Number of replacements applied (or that would be applied in dry-run mode)
+	pub replacements: usize,
+
+	/// Unified diff of the changes (only in dry-run mode)
+	pub diff: Option<String>,
+}
+
+/// Replaces the matches of `pattern` with `replacement` in each of the `files`
+/// (e.g., the result of `iter_files` or `list_files`).
+///
+/// - Files not passing [`SPath::is_likely_text`] are skipped.
+/// - Matching is done line by line, and line endings (`\n` or `\r\n`) are preserved.
+/// - For a `SearchPattern::Regex`, `replacement` can reference capture groups (e.g., `$1`).
+/// - Files are written with [`write_atomic`], so a failure never leaves a partially written file.
+///   A symlinked file is written through to its target, so the link itself is kept.
+/// - In dry-run mode (`ReplaceOptions::dry_run`), nothing is written, and each report has a unified diff.
+///
+/// Returns one [`ReplaceReport`] per changed file.
+pub fn replace_in_files<I>(
+	files: I,
+	pattern: impl Into<SearchPattern>,
+	replacement: &str,
+	replace_options: impl Into<ReplaceOptions>,
+) -> Result<Vec<ReplaceReport>>
+where
+	I: IntoIterator,
+	I::Item: AsRef<SPath>,
+{
+	let matcher = pattern.into().matcher();
+	let replace_options = replace_options.into();
+
+	let mut reports = Vec::new();
+	for file in files {
+		let file = file.as_ref();
+		if !file.is_likely_text() {
+			continue;
+		}
+		if let Some(report) = replace_in_file_with_matcher(file, &matcher, replacement, &replace_options)? {
+			reports.push(report);
+		}
+	}
+
+	Ok(reports)
+}
+
+/// Replaces the matches of `pattern` with `replacement` in a single file.
+///
+/// Note: This does not check `is_likely_text`. See [`replace_in_files`] for the rules.
+///
+/// Returns `None` if the file had no match (or was bigger than `replace_options.max_file_size`).
+pub fn replace_in_file(
+	path: impl AsRef<SPath>,
+	pattern: &SearchPattern,
+	replacement: &str,
+	replace_options: &ReplaceOptions,
+) -> Result<Option<ReplaceReport>> {
+	replace_in_file_with_matcher(path.as_ref(), &pattern.matcher(), replacement, replace_options)
+}
+
+fn replace_in_file_with_matcher(
+	path: &SPath,
+	matcher: &PatternMatcher,
+	replacement: &str,
+	replace_options: &ReplaceOptions,
+) -> Result<Option<ReplaceReport>> {
+	if let Some(max_file_size) = replace_options.max_file_size {
+		let size = path.metadata()?.len();
+		if size > max_file_size {
+			return Ok(None);
+		}
+	}
+
+	let content = fs::read(path).map_err(|err| Error::FileCantRead((path, err).into()))?;
+
+	// -- Replace line by line, copying the line endings as they are
+	let mut new_content: Vec<u8> = Vec::with_capacity(content.len());
+	// (line index, new line content) for the changed lines, used for the diff
+	let mut changes: Vec<(usize, Vec<u8>)> = Vec::new();
+	let mut replacements = 0;
+	let mut last = 0;
+
+	for (line_idx, (start, end)) in line_ranges(&content).enumerate() {
+		new_content.extend_from_slice(&content[last..start]);
+		match matcher.replace_line(&content[start..end], replacement) {
+			Some((new_line, count)) => {
+				new_content.extend_from_slice(&new_line);
+				replacements += count;
+				if replace_options.dry_run {
+					changes.push((line_idx, new_line));
+				}
+			}
+			None => new_content.extend_from_slice(&content[start..end]),
+		}
+		last = end;
+	}
+	new_content.extend_from_slice(&content[last..]);
+
+	if replacements == 0 {
+		return Ok(None);
+	}
+
+	// -- Write or diff
+	let diff = if replace_options.dry_run {
+		Some(unified_diff(path, &content, &changes))
+	} else {
+		// write_atomic renames over the path, which would replace a symlink with a regular file
+		let target = if path.as_std_path().is_symlink() {
+			path.canonicalize()?
+		} else {
+			path.clone()
+		};
+		write_atomic(&target, &new_content)?;
+		None
+	};
+
+	Ok(Some(ReplaceReport {
+		path: path.clone(),
+		replacements,
+		diff,
+	}))
+}
+
+// region:    --- Support
+
+/// Builds a unified diff from the original `content` and the changed lines.
+///
+/// `changes` is sorted by line index. A new line can contain `\n` (when the replacement has one),
+/// in which case it becomes multiple `+` lines.
+///
+/// When the file does not end with a newline, its last line is followed by `\ No newline at end of file`.
+fn unified_diff(path: &SPath, content: &[u8], changes: &[(usize, Vec<u8>)]) -> String {
+	const NO_NEWLINE_MARKER: &str = "\\ No newline at end of file\n";
+
+	let old_lines: Vec<String> = line_ranges(content)
+		.map(|(start, end)| String::from_utf8_lossy(&content[start..end]).into_owned())
+		.collect();
+	// Index of the last line when it has no trailing newline
+	let no_eol_idx = (!content.is_empty() && !content.ends_with(b"\n")).then(|| old_lines.len() - 1);
+	let new_lines: Vec<Vec<String>> = changes
+		.iter()
+		.map(|(_, line)| {
+			String::from_utf8_lossy(line)
+				.split('\n')
+				.map(|l| l.trim_end_matches('\r').to_string())
+				.collect()
+		})
+		.collect();
+
+	let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+
+	// Number of lines added so far by multi-line replacements (for the new line numbers)
+	let mut new_offset: isize = 0;
+	let mut i = 0;
+	while i < changes.len() {
+		// -- Determine the changes in this hunk (merge when contexts overlap)
+		let mut j = i + 1;
+		while j < changes.len() && changes[j].0 <= changes[j - 1].0 + 2 * DIFF_CONTEXT_LINES {
+			j += 1;
+		}
+
+		let hunk_start = changes[i].0.saturating_sub(DIFF_CONTEXT_LINES);
+		let hunk_end = (changes[j - 1].0 + DIFF_CONTEXT_LINES + 1).min(old_lines.len());
+
+		// -- Build the hunk body
+		let mut body = String::new();
+		let mut new_len = 0;
+		let mut change_idx = i;
+		for (line_idx, old_line) in old_lines.iter().enumerate().take(hunk_end).skip(hunk_start) {
+			let is_no_eol = no_eol_idx == Some(line_idx);
+			if change_idx < j && changes[change_idx].0 == line_idx {
+				body.push_str(&format!("-{old_line}\n"));
+				if is_no_eol {
+					body.push_str(NO_NEWLINE_MARKER);
+				}
+				for new_line in &new_lines[change_idx] {
+					body.push_str(&format!("+{new_line}\n"));
+				}
+				if is_no_eol && !changes[change_idx].1.ends_with(b"\n") {
+					body.push_str(NO_NEWLINE_MARKER);
+				}
+				new_len += new_lines[change_idx].len();
+				change_idx += 1;
+			} else {
+				body.push_str(&format!(" {old_line}\n"));
+				if is_no_eol {
+					body.push_str(NO_NEWLINE_MARKER);
+				}
+				new_len += 1;
+			}
+		}
+
+		let old_len = hunk_end - hunk_start;
+		let new_start = hunk_start as isize + new_offset + 1;
+		diff.push_str(&format!("@@ -{},{old_len} +{new_start},{new_len} @@\n", hunk_start + 1));
+		diff.push_str(&body);
+
+		new_offset += new_len as isize - old_len as isize;
+		i = j;
+	}
+
+	diff
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{ensure_dir, read_to_string};
+
+	#[test]
+	fn test_search_replace_in_files_dry_run_diff() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/replace-dry-run");
+		ensure_dir(&dir)?;
+		let file = dir.join("file.txt");
+		let content = "one\r\ntwo\r\nthree\r\nfour\r\nfive\r\nsix\r\nseven\r\neight\r\nnine\r\nten two\r\n";
+		fs::write(&file, content)?;
+
+		// -- Exec
+		let reports = replace_in_files([&file], "two", "2", ReplaceOptions::default().with_dry_run(true))?;
+
+		// -- Check
+		assert_eq!(fs::read_to_string(&file)?, content, "dry-run should not write");
+		let report = reports.first().ok_or("should have one report")?;
+		assert_eq!(report.replacements, 2);
+		let diff = report.diff.as_deref().ok_or("should have a diff")?;
+		let expected = format!(
+			"--- a/{file}\n+++ b/{file}\n\
+			@@ -1,5 +1,5 @@\n one\n-two\n+2\n three\n four\n five\n\
+			@@ -7,4 +7,4 @@\n seven\n eight\n nine\n-ten two\n+ten 2\n"
+		);
+		assert_eq!(diff, expected);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_search_replace_in_files_regex_write() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/replace-regex-write");
+		ensure_dir(&dir)?;
+		let file = dir.join("file.md");
+		fs::write(&file, "name: Alice\r\nname: Bob\nno name here")?;
+		let pattern = SearchPattern::regex(r"name: (\w+)")?;
+
+		// -- Exec
+		let reports = replace_in_files([&file], pattern, "user: ${1}!", ())?;
+
+		// -- Check
+		assert_eq!(reports.len(), 1);
+		assert_eq!(reports[0].replacements, 2);
+		assert!(reports[0].diff.is_none());
+		assert_eq!(read_to_string(&file)?, "user: Alice!\r\nuser: Bob!\nno name here");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_search_replace_in_files_dry_run_diff_no_newline_at_eof() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/replace-dry-run-no-eol");
+		ensure_dir(&dir)?;
+		let changed_last = dir.join("changed-last.txt");
+		fs::write(&changed_last, "one\ntwo")?;
+		let unchanged_last = dir.join("unchanged-last.txt");
+		fs::write(&unchanged_last, "two\none")?;
+		let options = ReplaceOptions::default().with_dry_run(true);
+
+		// -- Exec
+		let reports = replace_in_files([&changed_last, &unchanged_last], "two", "2", options)?;
+
+		// -- Check
+		assert_eq!(reports.len(), 2);
+		let diff = reports[0].diff.as_deref().ok_or("should have a diff")?;
+		let expected = format!(
+			"--- a/{changed_last}\n+++ b/{changed_last}\n\
+			@@ -1,2 +1,2 @@\n one\n-two\n\\ No newline at end of file\n+2\n\\ No newline at end of file\n"
+		);
+		assert_eq!(diff, expected);
+		let diff = reports[1].diff.as_deref().ok_or("should have a diff")?;
+		let expected = format!(
+			"--- a/{unchanged_last}\n+++ b/{unchanged_last}\n\
+			@@ -1,2 +1,2 @@\n-two\n+2\n one\n\\ No newline at end of file\n"
+		);
+		assert_eq!(diff, expected);
+
+		Ok(())
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_search_replace_in_files_symlink_kept() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/replace-symlink");
+		ensure_dir(&dir)?;
+		let target = dir.join("target.txt");
+		fs::write(&target, "name: Alice\n")?;
+		let link = dir.join("link.txt");
+		if link.as_std_path().is_symlink() {
+			fs::remove_file(&link)?;
+		}
+		std::os::unix::fs::symlink("target.txt", &link)?;
+
+		// -- Exec
+		let reports = replace_in_files([&link], "Alice", "Bob", ())?;
+
+		// -- Check
+		assert_eq!(reports.len(), 1);
+		assert_eq!(reports[0].path, link);
+		assert!(link.as_std_path().is_symlink(), "the link should be kept");
+		assert_eq!(read_to_string(&target)?, "name: Bob\n");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,34 @@
+#[derive(Debug, Clone, Default)]
+pub struct ReplaceOptions {
+	/// When true, files are not written, and each report contains a unified diff of the changes.
+	pub dry_run: bool,
+
+	/// Files larger than this size (in bytes) are skipped (None for no limit)
+	pub max_file_size: Option<u64>,
+}
+
+// region:    --- Froms
+
+impl From<()> for ReplaceOptions {
+	fn from(_: ()) -> Self {
+		Self::default()
+	}
+}
+
+// endregion: --- Froms
+
+// region:    --- Fluent API
+
+impl ReplaceOptions {
+	pub fn with_dry_run(mut self, val: bool) -> Self {
+		self.dry_run = val;
+		self
+	}
+
+	pub fn with_max_file_size(mut self, max_size: u64) -> Self {
+		self.max_file_size = Some(max_size);
+		self
+	}
+}
+
+// endregion: --- Fluent API
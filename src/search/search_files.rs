@@ -0,0 +1,327 @@
+use super::search_pattern::PatternMatcher;
+use crate::{Error, ListOptions, Result, SPath, SearchOptions, SearchPattern, get_buf_reader, iter_files};
+use memchr::memchr_iter;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+
+/// A single match found by [`search_files`] / [`search_file`].
+///
+/// The spans are absolute byte ranges [start, end) in the file, compatible with `read_span`.
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+	pub path: SPath,
+
+	/// 1-based line number
+	pub line_num: usize,
+
+	/// The span of the whole line (without the trailing `\n` or `\r\n`)
+	pub line_span: (usize, usize),
+
+	/// The span of the match itself
+	pub match_span: (usize, usize),
+
+	/// The line content (invalid UTF-8 replaced by `U+FFFD`)
+	pub line: String,
+}
+
+/// Returns an iterator of the matches of `pattern` in the text files listed from `dir`.
+///
+/// - Files are listed with [`iter_files`] (same `include_globs` and `list_options` semantics).
+/// - Only files passing [`SPath::is_likely_text`] are searched.
+/// - Files are read line by line as the iterator advances, so memory does not depend on the file sizes.
+/// - Matching is done line by line, so a match never spans multiple lines.
+pub fn iter_search_files(
+	dir: impl AsRef<Path>,
+	include_globs: Option<&[&str]>,
+	list_options: Option<ListOptions<'_>>,
+	pattern: impl Into<SearchPattern>,
+	search_options: impl Into<SearchOptions>,
+) -> Result<impl Iterator<Item = Result<SearchMatch>>> {
+	let matcher = Arc::new(pattern.into().matcher());
+	let search_options = search_options.into();
+
+	let files = iter_files(dir, include_globs, list_options)?;
+
+	let iter = files
+		.filter(|file| file.is_likely_text())
+		.flat_map(move |file| FileMatches::new(file, matcher.clone(), &search_options));
+
+	Ok(iter)
+}
+
+/// Collects the matches from [`iter_search_files`] into a `Vec<SearchMatch>`.
+///
+/// Fails on the first file that cannot be read.
+pub fn search_files(
+	dir: impl AsRef<Path>,
+	include_globs: Option<&[&str]>,
+	list_options: Option<ListOptions<'_>>,
+	pattern: impl Into<SearchPattern>,
+	search_options: impl Into<SearchOptions>,
+) -> Result<Vec<SearchMatch>> {
+	iter_search_files(dir, include_globs, list_options, pattern, search_options)?.collect()
+}
+
+/// Returns the matches of `pattern` in a single file.
+///
+/// Note: This does not check `is_likely_text`, but still honors `search_options.max_file_size`
+///       (returns an empty list when the file is too big).
+pub fn search_file(
+	path: impl AsRef<SPath>,
+	pattern: &SearchPattern,
+	search_options: &SearchOptions,
+) -> Result<Vec<SearchMatch>> {
+	FileMatches::new(path.as_ref().clone(), Arc::new(pattern.matcher()), search_options).collect()
+}
+
+// region:    --- FileMatches
+
+/// Iterator over the matches of one file, reading it line by line with a `BufReader`.
+///
+/// The file is opened on the first `next`, and the iterator ends after the first error.
+struct FileMatches {
+	path: SPath,
+	matcher: Arc<PatternMatcher>,
+	max_file_size: Option<u64>,
+	/// Number of matches left before `max_matches_per_file` is reached
+	remaining: usize,
+
+	/// `None` until the file is opened, and again once it is done (or skipped)
+	reader: Option<BufReader<File>>,
+	done: bool,
+
+	line_buf: Vec<u8>,
+	/// Absolute byte offset of the next line
+	offset: usize,
+	line_num: usize,
+	/// The matches of the current line not yet returned
+	pending: std::vec::IntoIter<SearchMatch>,
+}
+
+impl FileMatches {
+	fn new(path: SPath, matcher: Arc<PatternMatcher>, search_options: &SearchOptions) -> Self {
+		Self {
+			path,
+			matcher,
+			max_file_size: search_options.max_file_size,
+			remaining: search_options.max_matches_per_file.unwrap_or(usize::MAX),
+			reader: None,
+			done: false,
+			line_buf: Vec::new(),
+			offset: 0,
+			line_num: 0,
+			pending: Vec::new().into_iter(),
+		}
+	}
+
+	/// Opens the file on the first call (unless it is bigger than `max_file_size`).
+	fn ensure_open(&mut self) -> Result<()> {
+		if self.done || self.reader.is_some() {
+			return Ok(());
+		}
+		if let Some(max_file_size) = self.max_file_size
+			&& self.path.metadata()?.len() > max_file_size
+		{
+			self.done = true;
+			return Ok(());
+		}
+		self.reader = Some(get_buf_reader(&self.path)?);
+		Ok(())
+	}
+
+	/// Reads the next line into `line_buf` (line ending trimmed, same rules as `line_spans`)
+	/// and returns its span, or `None` at the end of the file.
+	fn read_line(&mut self) -> Result<Option<(usize, usize)>> {
+		self.line_buf.clear();
+		self.ensure_open()?;
+		let Some(reader) = self.reader.as_mut() else {
+			return Ok(None);
+		};
+		let read = reader
+			.read_until(b'\n', &mut self.line_buf)
+			.map_err(|err| Error::FileCantRead((&self.path, err).into()))?;
+		if read == 0 {
+			return Ok(None);
+		}
+
+		let line_start = self.offset;
+		self.offset += read;
+		if self.line_buf.last() == Some(&b'\n') {
+			self.line_buf.pop();
+			if self.line_buf.last() == Some(&b'\r') {
+				self.line_buf.pop();
+			}
+		}
+
+		Ok(Some((line_start, line_start + self.line_buf.len())))
+	}
+}
+
+impl Iterator for FileMatches {
+	type Item = Result<SearchMatch>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(m) = self.pending.next() {
+				return Some(Ok(m));
+			}
+			if self.remaining == 0 {
+				return None;
+			}
+
+			let (line_start, line_end) = match self.read_line() {
+				Ok(Some(span)) => span,
+				Ok(None) => {
+					self.done = true;
+					self.reader = None;
+					return None;
+				}
+				Err(err) => {
+					self.done = true;
+					self.reader = None;
+					return Some(Err(err));
+				}
+			};
+			self.line_num += 1;
+
+			let ranges = self.matcher.find_ranges(&self.line_buf);
+			if ranges.is_empty() {
+				continue;
+			}
+
+			let line = String::from_utf8_lossy(&self.line_buf).into_owned();
+			let take = ranges.len().min(self.remaining);
+			self.remaining -= take;
+			let matches: Vec<SearchMatch> = ranges
+				.into_iter()
+				.take(take)
+				.map(|(start, end)| SearchMatch {
+					path: self.path.clone(),
+					line_num: self.line_num,
+					line_span: (line_start, line_end),
+					match_span: (line_start + start, line_start + end),
+					line: line.clone(),
+				})
+				.collect();
+			self.pending = matches.into_iter();
+		}
+	}
+}
+
+// endregion: --- FileMatches
+
+// region:    --- Support
+
+/// Returns the [start, end) ranges of each line of `content` (trailing `\r` excluded),
+/// same rules as `line_spans`.
+pub(crate) fn line_ranges(content: &[u8]) -> impl Iterator<Item = (usize, usize)> + '_ {
+	let mut line_start = 0;
+	let mut newlines = memchr_iter(b'\n', content);
+
+	std::iter::from_fn(move || {
+		if let Some(nl_idx) = newlines.next() {
+			let end = if nl_idx > line_start && content[nl_idx - 1] == b'\r' {
+				nl_idx - 1
+			} else {
+				nl_idx
+			};
+			let range = (line_start, end);
+			line_start = nl_idx + 1;
+			Some(range)
+		} else if line_start < content.len() {
+			let range = (line_start, content.len());
+			line_start = content.len();
+			Some(range)
+		} else {
+			None
+		}
+	})
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::read_span;
+	use std::fs;
+
+	#[test]
+	fn test_search_search_files_literal_simple() -> Result<()> {
+		// -- Exec
+		let matches = search_files("tests-data", Some(&["**/*.txt"]), None, "deepest", ())?;
+
+		// -- Check
+		assert_eq!(matches.len(), 2);
+		for m in matches {
+			assert_eq!(m.line_num, 1);
+			assert_eq!(read_span(&m.path, m.match_span.0, m.match_span.1)?, "deepest");
+			assert_eq!(read_span(&m.path, m.line_span.0, m.line_span.1)?, m.line);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_search_search_files_regex_with_limit() -> Result<()> {
+		// -- Setup & Fixtures
+		let pattern = SearchPattern::regex(r"[A-Z][a-z]+,\d+")?;
+		let options = SearchOptions::default().with_max_matches_per_file(2);
+
+		// -- Exec
+		let matches = search_files("tests-data", Some(&["*.csv"]), None, pattern, options)?;
+
+		// -- Check
+		let found: Vec<(usize, &str)> = matches.iter().map(|m| (m.line_num, m.line.as_str())).collect();
+		assert_eq!(
+			found,
+			[(2, "Alice,30,\"hello, world\""), (3, "Bob,25,\"Line with \"\"quote\"\"\"")]
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_search_search_files_max_file_size() -> Result<()> {
+		// -- Setup & Fixtures
+		let options = SearchOptions::default().with_max_file_size(10);
+
+		// -- Exec
+		let matches = search_files("tests-data", Some(&["**/*.txt"]), None, "file", options)?;
+
+		// -- Check
+		assert!(matches.is_empty(), "all .txt files are bigger than 10 bytes");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_search_search_file_crlf_spans() -> Result<()> {
+		// -- Setup & Fixtures
+		let dir = SPath::new("target/tests-tmp/search-file-crlf");
+		crate::ensure_dir(&dir)?;
+		let file = dir.join("file.txt");
+		fs::write(&file, "a key\r\n\r\nkey key\r\nlast key")?;
+
+		// -- Exec
+		let matches = search_file(&file, &"key".into(), &SearchOptions::default())?;
+
+		// -- Check
+		let found: Vec<(usize, &str)> = matches.iter().map(|m| (m.line_num, m.line.as_str())).collect();
+		assert_eq!(found, [(1, "a key"), (3, "key key"), (3, "key key"), (4, "last key")]);
+		for m in &matches {
+			assert_eq!(read_span(&m.path, m.match_span.0, m.match_span.1)?, "key");
+			assert_eq!(read_span(&m.path, m.line_span.0, m.line_span.1)?, m.line);
+		}
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
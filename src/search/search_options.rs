@@ -0,0 +1,34 @@
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+	/// Max number of matches returned per file (None for no limit)
+	pub max_matches_per_file: Option<usize>,
+
+	/// Files larger than this size (in bytes) are skipped (None for no limit)
+	pub max_file_size: Option<u64>,
+}
+
+// region:    --- Froms
+
+impl From<()> for SearchOptions {
+	fn from(_: ()) -> Self {
+		Self::default()
+	}
+}
+
+// endregion: --- Froms
+
+// region:    --- Fluent API
+
+impl SearchOptions {
+	pub fn with_max_matches_per_file(mut self, max: usize) -> Self {
+		self.max_matches_per_file = Some(max);
+		self
+	}
+
+	pub fn with_max_file_size(mut self, max_size: u64) -> Self {
+		self.max_file_size = Some(max_size);
+		self
+	}
+}
+
+// endregion: --- Fluent API
@@ -0,0 +1,141 @@
+use crate::{Error, Result};
+use memchr::memmem;
+use regex::bytes::Regex;
+
+/// The pattern used by the search and replace APIs.
+///
+/// - `Literal` matches the exact bytes of the string (uses `memchr::memmem`).
+/// - `Regex` matches with a `regex::bytes::Regex`, applied line by line.
+///
+/// Note: `From<&str>` and `From<String>` create a `Literal`. Use [`SearchPattern::regex`] for a regex.
+#[derive(Debug, Clone)]
+pub enum SearchPattern {
+	Literal(String),
+	Regex(Regex),
+}
+
+/// Constructors
+impl SearchPattern {
+	pub fn literal(val: impl Into<String>) -> Self {
+		Self::Literal(val.into())
+	}
+
+	pub fn regex(val: &str) -> Result<Self> {
+		let re = Regex::new(val).map_err(|cause| Error::RegexCantNew {
+			regex: val.to_string(),
+			cause,
+		})?;
+		Ok(Self::Regex(re))
+	}
+}
+
+/// Matching
+impl SearchPattern {
+	/// Compiles the pattern once (e.g., the `memmem::Finder` of a literal) for matching many lines.
+	pub(crate) fn matcher(&self) -> PatternMatcher {
+		match self {
+			Self::Literal(lit) if lit.is_empty() => PatternMatcher::Empty,
+			Self::Literal(lit) => PatternMatcher::Literal(Box::new(memmem::Finder::new(lit.as_bytes()).into_owned())),
+			Self::Regex(re) => PatternMatcher::Regex(re.clone()),
+		}
+	}
+}
+
+// region:    --- PatternMatcher
+
+/// The compiled form of a [`SearchPattern`], built once per search or replace.
+#[derive(Debug, Clone)]
+pub(crate) enum PatternMatcher {
+	/// An empty literal, which never matches.
+	Empty,
+	Literal(Box<memmem::Finder<'static>>),
+	Regex(Regex),
+}
+
+impl PatternMatcher {
+	/// Returns the [start, end) byte ranges of the matches in `haystack` (relative to `haystack`).
+	/// Empty matches are ignored.
+	pub(crate) fn find_ranges(&self, haystack: &[u8]) -> Vec<(usize, usize)> {
+		match self {
+			Self::Empty => Vec::new(),
+			Self::Literal(finder) => {
+				let len = finder.needle().len();
+				finder.find_iter(haystack).map(|start| (start, start + len)).collect()
+			}
+			Self::Regex(re) => re
+				.find_iter(haystack)
+				.filter(|m| !m.is_empty())
+				.map(|m| (m.start(), m.end()))
+				.collect(),
+		}
+	}
+
+	/// Replaces the (non-empty) matches in `line` with `replacement`.
+	/// For `Regex`, `replacement` can reference capture groups (e.g., `$1`, `${name}`).
+	///
+	/// Returns the new line and the number of replacements, or `None` if there was no match.
+	pub(crate) fn replace_line(&self, line: &[u8], replacement: &str) -> Option<(Vec<u8>, usize)> {
+		let mut out = Vec::with_capacity(line.len());
+		let mut last = 0;
+		let mut count = 0;
+
+		match self {
+			Self::Empty | Self::Literal(_) => {
+				for (start, end) in self.find_ranges(line) {
+					out.extend_from_slice(&line[last..start]);
+					out.extend_from_slice(replacement.as_bytes());
+					last = end;
+					count += 1;
+				}
+			}
+			Self::Regex(re) => {
+				for caps in re.captures_iter(line) {
+					let Some(m) = caps.get(0).filter(|m| !m.is_empty()) else {
+						continue;
+					};
+					out.extend_from_slice(&line[last..m.start()]);
+					caps.expand(replacement.as_bytes(), &mut out);
+					last = m.end();
+					count += 1;
+				}
+			}
+		}
+
+		if count == 0 {
+			return None;
+		}
+		out.extend_from_slice(&line[last..]);
+
+		Some((out, count))
+	}
+}
+
+// endregion: --- PatternMatcher
+
+// region:    --- Froms
+
+impl From<&str> for SearchPattern {
+	fn from(val: &str) -> Self {
+		Self::Literal(val.to_string())
+	}
+}
+
+impl From<&String> for SearchPattern {
+	fn from(val: &String) -> Self {
+		Self::Literal(val.to_string())
+	}
+}
+
+impl From<String> for SearchPattern {
+	fn from(val: String) -> Self {
+		Self::Literal(val)
+	}
+}
+
+impl From<Regex> for SearchPattern {
+	fn from(val: Regex) -> Self {
+		Self::Regex(val)
+	}
+}
+
+// endregion: --- Froms
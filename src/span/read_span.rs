@@ -7,25 +7,145 @@ use std::os::unix::fs::FileExt as _;
 #[cfg(windows)]
 use std::os::windows::fs::FileExt as _;
 
+/// Max gap (in bytes) between two sorted spans for them to be merged into a single positioned read
+/// by [`read_spans_bytes`].
+const SPAN_COALESCE_GAP: usize = 4 * 1024;
+
+/// Max size (in bytes) of a coalesced group read by [`read_spans_bytes`].
+/// Past it, a new group (and positioned read) is started (a single larger span is still read at once).
+const SPAN_COALESCE_MAX_BYTES: usize = 1024 * 1024;
+
 /// Read a (start,end) half-open span and return a string.
 pub fn read_span(path: impl AsRef<SPath>, start: usize, end: usize) -> Result<String> {
+	let bytes = read_span_bytes(path, start, end)?;
+
+	let txt = String::from_utf8(bytes).map_err(|_| Error::SpanInvalidUtf8)?;
+
+	Ok(txt)
+}
+
+/// Read a (start,end) half-open span and return a string,
+/// replacing invalid UTF-8 sequences with `U+FFFD`.
+pub fn read_span_lossy(path: impl AsRef<SPath>, start: usize, end: usize) -> Result<String> {
+	let bytes = read_span_bytes(path, start, end)?;
+
+	let txt = match String::from_utf8(bytes) {
+		Ok(txt) => txt,
+		Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+	};
+
+	Ok(txt)
+}
+
+/// Read a (start,end) half-open span and return the raw bytes (no UTF-8 requirement).
+pub fn read_span_bytes(path: impl AsRef<SPath>, start: usize, end: usize) -> Result<Vec<u8>> {
+	let mut buf = Vec::new();
+	read_span_into(path, start, end, &mut buf)?;
+	Ok(buf)
+}
+
+/// Read a (start,end) half-open span into the given `buf`.
+///
+/// The `buf` is cleared and resized to the span length, so a buffer reused across calls
+/// will only allocate when a span is larger than its current capacity.
+pub fn read_span_into(path: impl AsRef<SPath>, start: usize, end: usize, buf: &mut Vec<u8>) -> Result<()> {
 	let len = end.checked_sub(start).ok_or(Error::SpanInvalidStartAfterEnd)?;
 
 	let path = path.as_ref();
 	let file = open_file(path)?;
 
-	let res = read_exact_at(&file, start as u64, len).map_err(|err| Error::FileCantRead((path, err).into()))?;
+	buf.clear();
+	buf.resize(len, 0);
 
-	let txt = String::from_utf8(res).map_err(|_| Error::SpanInvalidUtf8)?;
+	read_exact_at(&file, start as u64, buf).map_err(|err| Error::FileCantRead((path, err).into()))?;
 
-	Ok(txt)
+	Ok(())
+}
+
+/// Read multiple (start,end) half-open spans and return a string for each (same order as `spans`).
+///
+/// See [`read_spans_bytes`] for the read strategy.
+pub fn read_spans(path: impl AsRef<SPath>, spans: &[(usize, usize)]) -> Result<Vec<String>> {
+	let all_bytes = read_spans_bytes(path, spans)?;
+
+	all_bytes
+		.into_iter()
+		.map(|bytes| String::from_utf8(bytes).map_err(|_| Error::SpanInvalidUtf8))
+		.collect()
+}
+
+/// Read multiple (start,end) half-open spans and return the raw bytes for each (same order as `spans`).
+///
+/// The file is opened once, and the spans are sorted and coalesced when they overlap or are close
+/// to each other, so that many small spans result in few positioned reads.
+/// A coalesced group is capped at 1 MB, so dense spans over a large file do not become one huge read.
+pub fn read_spans_bytes(path: impl AsRef<SPath>, spans: &[(usize, usize)]) -> Result<Vec<Vec<u8>>> {
+	// -- Validate the spans
+	for &(start, end) in spans {
+		if start > end {
+			return Err(Error::SpanInvalidStartAfterEnd);
+		}
+	}
+
+	let path = path.as_ref();
+	let file = open_file(path)?;
+
+	// -- Sort the span indexes by start
+	let mut order: Vec<usize> = (0..spans.len()).collect();
+	order.sort_by_key(|&idx| spans[idx]);
+
+	let mut res: Vec<Vec<u8>> = vec![Vec::new(); spans.len()];
+	let mut chunk: Vec<u8> = Vec::new();
+
+	// -- Read each coalesced group
+	let mut i = 0;
+	while i < order.len() {
+		let group_start = spans[order[i]].0;
+		let (j, group_end) = coalesce_group(spans, &order, i, SPAN_COALESCE_MAX_BYTES);
+
+		chunk.clear();
+		chunk.resize(group_end - group_start, 0);
+		read_exact_at(&file, group_start as u64, &mut chunk).map_err(|err| Error::FileCantRead((path, err).into()))?;
+
+		for &idx in &order[i..j] {
+			let (start, end) = spans[idx];
+			res[idx] = chunk[start - group_start..end - group_start].to_vec();
+		}
+
+		i = j;
+	}
+
+	Ok(res)
 }
 
 // region:    --- Support
 
-/// Read exactly `len` bytes starting at absolute file offset `offset` into a Vec.
-fn read_exact_at(file: &File, offset: u64, len: usize) -> io::Result<Vec<u8>> {
-	let mut buf = vec![0u8; len];
+/// Returns the end index (exclusive, in `order`) and the end offset of the group starting at `order[i]`.
+///
+/// The group is extended while the next span starts within the gap
+/// and the group stays within `max_group_bytes`.
+fn coalesce_group(spans: &[(usize, usize)], order: &[usize], i: usize, max_group_bytes: usize) -> (usize, usize) {
+	let group_start = spans[order[i]].0;
+	let mut group_end = spans[order[i]].1;
+
+	let mut j = i + 1;
+	while j < order.len() {
+		let (next_start, next_end) = spans[order[j]];
+		if next_start > group_end.saturating_add(SPAN_COALESCE_GAP)
+			|| group_end.max(next_end) - group_start > max_group_bytes
+		{
+			break;
+		}
+		group_end = group_end.max(next_end);
+		j += 1;
+	}
+
+	(j, group_end)
+}
+
+/// Read exactly `buf.len()` bytes starting at absolute file offset `offset` into `buf`.
+pub(crate) fn read_exact_at(file: &File, offset: u64, buf: &mut [u8]) -> io::Result<()> {
+	let len = buf.len();
 	let mut filled = 0usize;
 
 	while filled < len {
@@ -42,7 +162,87 @@ fn read_exact_at(file: &File, offset: u64, len: usize) -> io::Result<Vec<u8>> {
 		}
 		filled += n;
 	}
-	Ok(buf)
+	Ok(())
 }
 
 // endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_span_read_span_into_reuse_buf() -> Result<()> {
+		// -- Setup & Fixtures
+		let path = SPath::from("tests-data/example.csv");
+		let mut buf = Vec::new();
+
+		// -- Exec & Check
+		read_span_into(&path, 0, 4, &mut buf)?;
+		assert_eq!(&buf, b"name");
+		read_span_into(&path, 17, 22, &mut buf)?;
+		assert_eq!(&buf, b"Alice");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_span_read_spans_unordered_and_overlapping() -> Result<()> {
+		// -- Setup & Fixtures
+		let path = SPath::from("tests-data/example.csv");
+		let spans = [(17, 22), (0, 4), (5, 8), (0, 16), (9, 9)];
+
+		// -- Exec
+		let res = read_spans(&path, &spans)?;
+
+		// -- Check
+		assert_eq!(res, ["Alice", "name", "age", "name,age,comment", ""]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_span_read_spans_coalesce_group_max_bytes() -> Result<()> {
+		// -- Setup & Fixtures
+		// 100 spans of 10 bytes, 100 bytes apart (all within the gap)
+		let spans: Vec<(usize, usize)> = (0..100).map(|i| (i * 100, i * 100 + 10)).collect();
+		let order: Vec<usize> = (0..spans.len()).collect();
+
+		// -- Exec
+		let (j_uncapped, _) = coalesce_group(&spans, &order, 0, usize::MAX);
+		let (j_capped, end_capped) = coalesce_group(&spans, &order, 0, 1_000);
+		let (j_single, end_single) = coalesce_group(&[(0, 5_000), (5_001, 5_002)], &[0, 1], 0, 1_000);
+
+		// -- Check
+		assert_eq!(j_uncapped, 100);
+		assert_eq!(j_capped, 10);
+		assert_eq!(end_capped, 910);
+		assert_eq!(
+			(j_single, end_single),
+			(1, 5_000),
+			"a larger single span is still one group"
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_span_read_spans_out_of_bounds() -> Result<()> {
+		// -- Setup & Fixtures
+		let path = SPath::from("tests-data/example.csv");
+
+		// -- Exec
+		let res = read_spans_bytes(&path, &[(0, 4), (100_000, 100_010)]);
+
+		// -- Check
+		assert!(matches!(res, Err(Error::FileCantRead(_))));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,10 +1,10 @@
-use crate::{Error, Result, SMeta, reshape};
+use crate::{Error, ExpandOptions, Result, SMeta, home_dir, reshape};
 use camino::{Utf8Path, Utf8PathBuf};
 use core::fmt;
 use pathdiff::diff_utf8_paths;
 use std::fs::{self, Metadata};
 use std::path::{Path, PathBuf};
-use std::time::UNIX_EPOCH;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// An SPath is a posix normalized Path using camino Utf8PathBuf as strogate.
 /// It can be constructed from a String, Path, io::DirEntry, or walkdir::DirEntry
@@ -277,11 +277,35 @@ impl SPath {
 	/// Get a Simple Metadata structure `SMeta` with
 	/// created_epoch_us, modified_epoch_us, and size (all i64)
 	/// (size will be '0' for any none file)
-	#[allow(clippy::fn_to_numeric_cast)]
+	///
+	/// Follows the symlinks (`is_symlink` is still about the path itself).
 	pub fn meta(&self) -> Result<SMeta> {
-		let path = self;
+		// -- Get the metadata of the path itself, and only follow it when it is a symlink
+		let link_metadata = fs::symlink_metadata(self).map_err(|ex| Error::CantGetMetadata((self, ex).into()))?;
+		if !link_metadata.is_symlink() {
+			return self.meta_from_metadata(&link_metadata, false);
+		}
 
 		let metadata = self.metadata()?;
+		self.meta_from_metadata(&metadata, true)
+	}
+
+	/// Same as `meta()` but does not follow the symlinks
+	/// (the `SMeta` of a symlink is about the link itself).
+	pub fn meta_no_follow(&self) -> Result<SMeta> {
+		let metadata = fs::symlink_metadata(self).map_err(|ex| Error::CantGetMetadata((self, ex).into()))?;
+		self.meta_from_metadata(&metadata, metadata.is_symlink())
+	}
+
+	/// Returns the std metadata
+	pub fn metadata(&self) -> Result<Metadata> {
+		fs::metadata(self).map_err(|ex| Error::CantGetMetadata((self, ex).into()))
+	}
+
+	/// Builds the `SMeta` of this path from already fetched metadata (e.g., from a walkdir entry).
+	#[allow(clippy::fn_to_numeric_cast)]
+	pub(crate) fn meta_from_metadata(&self, metadata: &Metadata, is_symlink: bool) -> Result<SMeta> {
+		let path = self;
 
 		// -- Get modified (failed if it cannot)
 		let modified = metadata.modified().map_err(|ex| Error::CantGetMetadata((path, ex).into()))?;
@@ -291,29 +315,113 @@ impl SPath {
 			.as_micros()
 			.min(i64::MAX as u128) as i64;
 
-		// -- Get created (If not found, will get modified)
-		let created_epoch_us = metadata
-			.modified()
-			.ok()
-			.and_then(|c| c.duration_since(UNIX_EPOCH).ok())
-			.map(|c| c.as_micros().min(i64::MAX as u128) as i64);
+		// -- Get created (If not available, e.g., no statx birth time, will get modified)
+		let created_epoch_us = metadata.created().ok().and_then(epoch_us);
+		let created_is_real = created_epoch_us.is_some();
 		let created_epoch_us = created_epoch_us.unwrap_or(modified_epoch_us);
 
+		// -- Get accessed (If not found, will get modified)
+		let accessed_epoch_us = metadata.accessed().ok().and_then(epoch_us).unwrap_or(modified_epoch_us);
+
 		// -- Get size
 		let size = if metadata.is_file() { metadata.len() } else { 0 };
 
+		// -- Get unix fields
+		#[cfg(unix)]
+		let (mode, uid, gid, inode, dev, nlink) = {
+			use std::os::unix::fs::MetadataExt;
+			(
+				metadata.mode(),
+				metadata.uid(),
+				metadata.gid(),
+				metadata.ino(),
+				metadata.dev(),
+				metadata.nlink(),
+			)
+		};
+		#[cfg(not(unix))]
+		let (mode, uid, gid, inode, dev, nlink) = (0, 0, 0, 0, 0, 0);
+
 		Ok(SMeta {
 			created_epoch_us,
+			created_is_real,
 			modified_epoch_us,
 			size,
 			is_file: metadata.is_file(),
 			is_dir: metadata.is_dir(),
+			is_symlink,
+			accessed_epoch_us,
+			mode,
+			uid,
+			gid,
+			inode,
+			dev,
+			nlink,
 		})
 	}
+}
 
-	/// Returns the std metadata
-	pub fn metadata(&self) -> Result<Metadata> {
-		fs::metadata(self).map_err(|ex| Error::CantGetMetadata((self, ex).into()))
+/// Permissions & Times
+impl SPath {
+	/// Sets the unix permission bits (e.g., `0o644`).
+	///
+	/// Note: On non-unix, only the read-only flag is set (read-only when `mode` has no write bit).
+	pub fn set_permissions(&self, mode: u32) -> Result<()> {
+		#[cfg(unix)]
+		let permissions = {
+			use std::os::unix::fs::PermissionsExt;
+			fs::Permissions::from_mode(mode)
+		};
+		#[cfg(not(unix))]
+		let permissions = {
+			let mut permissions = self.metadata()?.permissions();
+			permissions.set_readonly(mode & 0o222 == 0);
+			permissions
+		};
+
+		fs::set_permissions(self, permissions).map_err(|ex| Error::CantSetMetadata((self, ex).into()))
+	}
+
+	/// Adds the execute bit wherever the read bit is set (e.g., `0o644` becomes `0o755`).
+	///
+	/// Note: No-op on non-unix.
+	pub fn make_executable(&self) -> Result<()> {
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			let mode = self.metadata()?.permissions().mode() & 0o7777;
+			let new_mode = mode | ((mode & 0o444) >> 2);
+			if new_mode != mode {
+				self.set_permissions(new_mode)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Sets the modification time (since the Unix epoch in microseconds).
+	pub fn set_modified(&self, epoch_us: i64) -> Result<()> {
+		let duration = Duration::from_micros(epoch_us.unsigned_abs());
+		let time = if epoch_us >= 0 {
+			UNIX_EPOCH.checked_add(duration)
+		} else {
+			UNIX_EPOCH.checked_sub(duration)
+		};
+		let time = time.ok_or_else(|| {
+			Error::CantSetMetadata(crate::error::PathAndCause {
+				path: self.to_string(),
+				cause: format!("Time out of range (epoch_us: {epoch_us})").into(),
+			})
+		})?;
+
+		// NOTE: On Windows, setting the times requires a write handle
+		let file = if cfg!(windows) {
+			fs::OpenOptions::new().write(true).open(self)
+		} else {
+			fs::File::open(self)
+		};
+		file.and_then(|file| file.set_modified(time))
+			.map_err(|ex| Error::CantSetMetadata((self, ex).into()))
 	}
 }
 
@@ -547,6 +655,39 @@ impl SPath {
 	}
 }
 
+/// Expand & Contract
+impl SPath {
+	/// Expands a leading `~` with the home directory and the `$VAR` / `${VAR}` environment variables
+	/// (undefined variables are kept as is, see `expand_with` for the strict mode).
+	///
+	/// e.g., `~/data/${PROJECT}/out` to `/home/user/data/demo/out`
+	pub fn expand(&self) -> Result<SPath> {
+		self.expand_with(())
+	}
+
+	/// Same as `expand()` with the variables from a supplied map (instead of the environment)
+	/// and/or the strict mode (undefined variable is an `Error::PathExpandVarUndefined`).
+	pub fn expand_with<'a>(&self, options: impl Into<ExpandOptions<'a>>) -> Result<SPath> {
+		let expanded = reshape::expand_path_str(self.as_str(), &options.into())?;
+		Ok(SPath::new(expanded))
+	}
+
+	/// Replaces the home directory prefix (component-aware) with `~` (e.g., for display).
+	///
+	/// Returns the path as is if it is not below the home directory (or if there is no home directory).
+	pub fn contract_home(&self) -> SPath {
+		let Ok(home) = home_dir() else {
+			return self.clone();
+		};
+
+		match self.path_buf.strip_prefix(home.path()) {
+			Ok(rest) if rest.as_str().is_empty() => SPath::new("~"),
+			Ok(rest) => SPath::new(format!("~/{rest}")),
+			Err(_) => self.clone(),
+		}
+	}
+}
+
 /// Extensions
 impl SPath {
 	/// Consumes the SPath and returns one with the given extension ensured:
@@ -763,6 +904,16 @@ pub(crate) fn validate_spath_for_option(path: impl Into<PathBuf>) -> Option<Utf8
 
 // endregion: --- Path Validation
 
+// region:    --- Support
+
+/// Returns the time since the Unix epoch in microseconds (None if before the epoch).
+fn epoch_us(time: SystemTime) -> Option<i64> {
+	let duration = time.duration_since(UNIX_EPOCH).ok()?;
+	Some(duration.as_micros().min(i64::MAX as u128) as i64)
+}
+
+// endregion: --- Support
+
 // region:    --- Tests
 
 #[cfg(test)]
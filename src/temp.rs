@@ -0,0 +1,260 @@
+use crate::error::PathAndCause;
+use crate::{Error, Result, SPath, SaferRemoveOptions, ensure_dir, safer_remove_dir, safer_remove_file};
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The prefix of the temporary directory and file names.
+const TEMP_NAME_PREFIX: &str = "sfs-tmp-";
+
+/// Max attempts to find a unique name (on collision).
+const TEMP_NAME_MAX_ATTEMPTS: u32 = 100;
+
+static TEMP_NAME_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// region:    --- STempDir
+
+/// A uniquely named temporary directory, removed (with its content) when dropped.
+///
+/// The removal goes through `safer_remove_dir` with the temp base as the only allowed root,
+/// so it can never delete outside of the base.
+///
+/// Use `keep()` to disable the removal (e.g., for debugging).
+#[derive(Debug)]
+pub struct STempDir {
+	path: SPath,
+	base: SPath,
+	keep: bool,
+}
+
+/// Constructors
+impl STempDir {
+	/// Creates a temporary directory in the system temp directory (`std::env::temp_dir()`).
+	pub fn new() -> Result<Self> {
+		Self::new_in(std::env::temp_dir())
+	}
+
+	/// Creates a temporary directory in `base` (created if missing).
+	pub fn new_in(base: impl AsRef<Path>) -> Result<Self> {
+		let base = resolve_base(base.as_ref())?;
+		let path = create_unique(&base, "", |path| fs::create_dir(path))?;
+		Ok(Self {
+			path,
+			base,
+			keep: false,
+		})
+	}
+}
+
+/// Getters & Keep
+impl STempDir {
+	/// The absolute path of the temporary directory.
+	pub fn path(&self) -> &SPath {
+		&self.path
+	}
+
+	/// Disables the removal on drop and returns the path.
+	pub fn keep(mut self) -> SPath {
+		self.keep = true;
+		self.path.clone()
+	}
+}
+
+impl Drop for STempDir {
+	fn drop(&mut self) {
+		if self.keep {
+			return;
+		}
+		let roots = [self.base.as_str()];
+		let _ = safer_remove_dir(&self.path, temp_remove_options(&roots));
+	}
+}
+
+impl AsRef<SPath> for STempDir {
+	fn as_ref(&self) -> &SPath {
+		&self.path
+	}
+}
+
+impl AsRef<Path> for STempDir {
+	fn as_ref(&self) -> &Path {
+		self.path.std_path()
+	}
+}
+
+// endregion: --- STempDir
+
+// region:    --- STempFile
+
+/// A uniquely named temporary file (created empty), removed when dropped.
+///
+/// The removal goes through `safer_remove_file` with the temp base as the only allowed root.
+///
+/// Use `keep()` to disable the removal (e.g., for debugging).
+#[derive(Debug)]
+pub struct STempFile {
+	path: SPath,
+	base: SPath,
+	keep: bool,
+}
+
+/// Constructors
+impl STempFile {
+	/// Creates a temporary file in the system temp directory (`std::env::temp_dir()`).
+	pub fn new() -> Result<Self> {
+		Self::new_in(std::env::temp_dir())
+	}
+
+	/// Creates a temporary file in `base` (created if missing).
+	pub fn new_in(base: impl AsRef<Path>) -> Result<Self> {
+		Self::new_in_with_ext(base, "")
+	}
+
+	/// Creates a temporary file with the extension `ext` (e.g., `"json"`) in `base` (created if missing).
+	pub fn new_in_with_ext(base: impl AsRef<Path>, ext: &str) -> Result<Self> {
+		let base = resolve_base(base.as_ref())?;
+		let suffix = if ext.is_empty() {
+			String::new()
+		} else {
+			format!(".{ext}")
+		};
+		let path = create_unique(&base, &suffix, |path| {
+			OpenOptions::new().write(true).create_new(true).open(path).map(|_| ())
+		})?;
+		Ok(Self {
+			path,
+			base,
+			keep: false,
+		})
+	}
+}
+
+/// Getters & Keep
+impl STempFile {
+	/// The absolute path of the temporary file.
+	pub fn path(&self) -> &SPath {
+		&self.path
+	}
+
+	/// Disables the removal on drop and returns the path.
+	pub fn keep(mut self) -> SPath {
+		self.keep = true;
+		self.path.clone()
+	}
+}
+
+impl Drop for STempFile {
+	fn drop(&mut self) {
+		if self.keep {
+			return;
+		}
+		let roots = [self.base.as_str()];
+		let _ = safer_remove_file(&self.path, temp_remove_options(&roots));
+	}
+}
+
+impl AsRef<SPath> for STempFile {
+	fn as_ref(&self) -> &SPath {
+		&self.path
+	}
+}
+
+impl AsRef<Path> for STempFile {
+	fn as_ref(&self) -> &Path {
+		self.path.std_path()
+	}
+}
+
+// endregion: --- STempFile
+
+// region:    --- Support
+
+/// Ensures and canonicalizes the base (so that it can be used as the allowed root).
+fn resolve_base(base: &Path) -> Result<SPath> {
+	ensure_dir(base)?;
+	SPath::from_std_path(base)?.canonicalize()
+}
+
+fn temp_remove_options<'a>(roots: &'a [&'a str]) -> SaferRemoveOptions<'a> {
+	SaferRemoveOptions::default()
+		.with_restrict_to_current_dir(false)
+		.with_allowed_roots(roots)
+		.with_clear_readonly(true)
+}
+
+/// Creates a uniquely named entry in `base` with `create`, which must fail with `AlreadyExists` on collision.
+fn create_unique(base: &SPath, suffix: &str, create: impl Fn(&Path) -> std::io::Result<()>) -> Result<SPath> {
+	let pid = std::process::id();
+	for _ in 0..TEMP_NAME_MAX_ATTEMPTS {
+		let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+		let count = TEMP_NAME_COUNTER.fetch_add(1, Ordering::Relaxed);
+		let path = base.join(format!("{TEMP_NAME_PREFIX}{pid:x}-{nanos:x}-{count:x}{suffix}"));
+
+		match create(path.std_path()) {
+			Ok(()) => return Ok(path),
+			Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+			Err(err) => return Err(Error::FileCantCreate((path.std_path(), err).into())),
+		}
+	}
+
+	Err(Error::FileCantCreate(PathAndCause {
+		path: base.to_string(),
+		cause: format!("No unique temp name after {TEMP_NAME_MAX_ATTEMPTS} attempts").into(),
+	}))
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_temp_dir_drop_and_keep() -> Result<()> {
+		// -- Setup & Fixtures
+		let base = "target/tests-tmp/temp-dir";
+
+		// -- Exec
+		let temp_dir = STempDir::new_in(base)?;
+		let dropped_path = temp_dir.path().clone();
+		fs::write(temp_dir.path().join("data.txt"), "some data")?;
+		let exists_before_drop = dropped_path.exists();
+		drop(temp_dir);
+
+		let kept_path = STempDir::new_in(base)?.keep();
+
+		// -- Check
+		assert!(exists_before_drop);
+		assert!(!dropped_path.exists());
+		assert!(dropped_path.is_absolute());
+		assert!(kept_path.exists());
+		assert_ne!(dropped_path, kept_path);
+		fs::remove_dir(&kept_path)?;
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_temp_file_drop_with_ext() -> Result<()> {
+		// -- Exec
+		let temp_file = STempFile::new_in_with_ext("target/tests-tmp/temp-file", "json")?;
+		let path = temp_file.path().clone();
+		let exists_before_drop = path.is_file();
+		drop(temp_file);
+
+		// -- Check
+		assert!(exists_before_drop);
+		assert_eq!(path.ext(), "json");
+		assert!(!path.exists());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests